@@ -0,0 +1,39 @@
+//!
+//! Compares [`convert_rgb_to_hsl_bulk`] against converting each color
+//! individually. Run with `cargo bench`, optionally with `--features simd`.
+//!
+
+use color_conv::{convert_rgb_to_hsl_bulk, Color, Hsl, Rgb};
+use rand_chacha::{
+	rand_core::{RngCore, SeedableRng},
+	ChaCha8Rng,
+};
+use std::{hint::black_box, time::Instant};
+
+const PIXELS: usize = 1 << 20;
+const ROUNDS: u32 = 20;
+
+fn main() {
+	let mut rng = ChaCha8Rng::seed_from_u64(0);
+	let input: Vec<Rgb> = (0..PIXELS).map(|_| Rgb::from_u32(rng.next_u32())).collect();
+	let mut output = vec![Hsl::default(); PIXELS];
+
+	let start = Instant::now();
+	for _ in 0..ROUNDS {
+		for (rgb, hsl) in black_box(&input).iter().zip(output.iter_mut()) {
+			*hsl = rgb.to_hsl();
+		}
+		black_box(&output);
+	}
+	let scalar = start.elapsed() / ROUNDS;
+
+	let start = Instant::now();
+	for _ in 0..ROUNDS {
+		convert_rgb_to_hsl_bulk(black_box(&input), &mut output);
+		black_box(&output);
+	}
+	let bulk = start.elapsed() / ROUNDS;
+
+	println!("element-wise: {:?} per {} pixels", scalar, PIXELS);
+	println!("bulk:         {:?} per {} pixels", bulk, PIXELS);
+}
@@ -39,10 +39,7 @@ fn main() -> Result<()> {
 				let rgb = hsl.to_rgb();
 
 				// Print in true color!
-				println!(
-					"\x1b[38;2;{};{};{}mHello, world!\x1b[0m",
-					rgb.red, rgb.green, rgb.blue
-				)
+				println!("{}Hello, world!\x1b[0m", rgb.ansi_fg())
 			}
 			Err(ReadlineError::Interrupted) => {
 				eprintln!("CTRL-C");
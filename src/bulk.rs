@@ -0,0 +1,179 @@
+use crate::prelude::*;
+#[cfg(feature = "simd")]
+use crate::rgb;
+
+///
+/// Converts every color in `input` to [`Hsl`], writing the results into the
+/// matching positions of `output`. The results are always identical to
+/// calling [`Color::to_hsl`] on each color.
+///
+/// With the `simd` feature enabled, the buffer is processed in fixed-width
+/// chunks laid out as one array per channel, and the extrema, hue,
+/// saturation, and lightness of each chunk are computed without branches so
+/// that the compiler can vectorize them on stable Rust. Only the final
+/// rounding is done per color. Without it, this is a plain loop (see
+/// [`Rgb::slice_to_hsl_into`]).
+///
+/// # Arguments
+///
+/// * `input` - the colors to convert
+/// * `output` - the buffer to write into
+///
+/// # Panics
+///
+/// Panics if `input` and `output` have different lengths.
+///
+/// # Examples
+///
+/// ```
+/// use color_conv::{convert_rgb_to_hsl_bulk, Hsl, Rgb};
+///
+/// let input = vec![Rgb::new(0, 255, 255); 100];
+/// let mut output = vec![Hsl::default(); 100];
+/// convert_rgb_to_hsl_bulk(&input, &mut output);
+///
+/// assert!(output.iter().all(|&hsl| hsl == Hsl::new_unchecked(180, 100, 50)));
+/// ```
+///
+pub fn convert_rgb_to_hsl_bulk(input: &[Rgb], output: &mut [Hsl]) {
+	assert_eq!(
+		input.len(),
+		output.len(),
+		"input and output slices must have the same length"
+	);
+
+	#[cfg(feature = "simd")]
+	{
+		let mut input_chunks = input.chunks_exact(LANES);
+		let mut output_chunks = output.chunks_exact_mut(LANES);
+
+		for (input, output) in (&mut input_chunks).zip(&mut output_chunks) {
+			convert_lanes(input, output);
+		}
+
+		Rgb::slice_to_hsl_into(input_chunks.remainder(), output_chunks.into_remainder());
+	}
+
+	#[cfg(not(feature = "simd"))]
+	Rgb::slice_to_hsl_into(input, output);
+}
+
+#[cfg(feature = "simd")]
+const LANES: usize = 16;
+
+// Computes every lane without branches, so that the compiler can turn each
+// loop into vector instructions: the extrema are taken on the integer
+// channels, and all three hue candidates are computed and then selected. The
+// channel deltas over `delta` are within `[-1, 1]`, so this matches the `% 6`
+// and `rem_euclid` of `Rgb::to_hsl` exactly, and the final rounding is shared
+// with it
+#[cfg(feature = "simd")]
+fn convert_lanes(input: &[Rgb], output: &mut [Hsl]) {
+	let mut red: [u8; LANES] = [0; LANES];
+	let mut green: [u8; LANES] = [0; LANES];
+	let mut blue: [u8; LANES] = [0; LANES];
+
+	for (lane, rgb) in input.iter().enumerate() {
+		red[lane] = rgb.red;
+		green[lane] = rgb.green;
+		blue[lane] = rgb.blue;
+	}
+
+	let mut hue: [Float; LANES] = [0.; LANES];
+	let mut saturation: [Float; LANES] = [0.; LANES];
+	let mut lightness: [Float; LANES] = [0.; LANES];
+
+	for lane in 0..LANES {
+		let max = red[lane].max(green[lane]).max(blue[lane]);
+		let min = red[lane].min(green[lane]).min(blue[lane]);
+
+		let r = red[lane] as Float / 255.;
+		let g = green[lane] as Float / 255.;
+		let b = blue[lane] as Float / 255.;
+		let c_max = max as Float / 255.;
+		let c_min = min as Float / 255.;
+
+		let delta = c_max - c_min;
+		let gray = delta < Float::EPSILON;
+		let l = (c_max + c_min) / 2.;
+
+		let red_hue = 60. * ((g - b) / delta);
+		let red_hue = if red_hue < 0. {
+			red_hue + 360.
+		} else {
+			red_hue
+		};
+		let green_hue = 60. * (((b - r) / delta) + 2.);
+		let blue_hue = 60. * (((r - g) / delta) + 4.);
+
+		let h = if max == red[lane] {
+			red_hue
+		} else if max == green[lane] {
+			green_hue
+		} else {
+			blue_hue
+		};
+
+		hue[lane] = if gray { 0. } else { h };
+		saturation[lane] = if gray {
+			0.
+		} else {
+			delta / (1. - ((2. * l) - 1.).abs()) * 100.
+		};
+		lightness[lane] = l * 100.;
+	}
+
+	for (lane, hsl) in output.iter_mut().enumerate() {
+		*hsl = rgb::round_hsl((hue[lane], saturation[lane], lightness[lane]));
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use rand_chacha::{
+		rand_core::{RngCore, SeedableRng},
+		ChaCha8Rng,
+	};
+
+	#[test]
+	fn test_matches_scalar() {
+		let mut rng = ChaCha8Rng::seed_from_u64(0);
+		let input: Vec<Rgb> = (0..100_003)
+			.map(|_| Rgb::from_u32(rng.next_u32()))
+			.collect();
+
+		let mut output = vec![Hsl::default(); input.len()];
+		convert_rgb_to_hsl_bulk(&input, &mut output);
+
+		for (rgb, hsl) in input.iter().zip(output.iter()) {
+			assert_eq!(*hsl, rgb.to_hsl(), "{:?}", rgb);
+		}
+	}
+
+	#[test]
+	fn test_grays_and_primaries() {
+		let input = [
+			Rgb::new(0, 0, 0),
+			Rgb::new(255, 255, 255),
+			Rgb::new(128, 128, 128),
+			Rgb::new(255, 0, 0),
+			Rgb::new(0, 255, 0),
+			Rgb::new(0, 0, 255),
+			Rgb::new(255, 0, 1),
+			Rgb::new(255, 255, 0),
+			Rgb::new(0, 255, 255),
+		];
+
+		let mut output = [Hsl::default(); 9];
+		convert_rgb_to_hsl_bulk(&input, &mut output);
+
+		assert_eq!(output[..], Rgb::slice_to_hsl(&input)[..]);
+	}
+
+	#[should_panic]
+	#[test]
+	fn test_length_mismatch() {
+		convert_rgb_to_hsl_bulk(&[Rgb::new(0, 0, 0)], &mut []);
+	}
+}
@@ -0,0 +1,300 @@
+use crate::prelude::*;
+use core::{convert::TryFrom, fmt};
+
+///
+/// A representation of the CMY (cyan, magenta, yellow) color format, which
+/// is [`Cmyk`] without the key (black) channel.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cmy {
+	/// Cyan value (percentage)
+	pub cyan: u8,
+	/// Magenta value (percentage)
+	pub magenta: u8,
+	/// Yellow value (percentage)
+	pub yellow: u8,
+}
+
+impl Cmy {
+	///
+	/// Returns a Result containing a new Cmy object given cyan, magenta, and
+	/// yellow values. Will return an [`Error`] if any of the arguments are
+	/// larger than 100 due to the fact that they represent percentages.
+	///
+	/// # Arguments
+	///
+	/// * `cyan` - the cyan value of the color
+	/// * `magenta` - the magenta value of the color
+	/// * `yellow` - the yellow value of the color
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Cmy;
+	/// let cyan = Cmy::new(100, 0, 0)?;
+	/// # Ok::<(), color_conv::Error>(())
+	/// ```
+	///
+	pub fn new(cyan: u8, magenta: u8, yellow: u8) -> Result<Self, Error> {
+		if ![cyan, magenta, yellow].iter().all(|val| *val <= 100) {
+			return Err(Error::PercentageOverflow);
+		}
+
+		Ok(Self::new_unchecked(cyan, magenta, yellow))
+	}
+
+	///
+	/// See [`Cmy::new`](self::Cmy::new). Does not perform check to ensure
+	/// that all parameters are less than or equal to 100. This is useful for
+	/// when you know more than the compiler about which values are being passed
+	/// to the method.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Cmy;
+	/// let cyan = Cmy::new_unchecked(100, 0, 0);
+	/// ```
+	///
+	pub const fn new_unchecked(cyan: u8, magenta: u8, yellow: u8) -> Self {
+		Self {
+			cyan,
+			magenta,
+			yellow,
+		}
+	}
+
+	///
+	/// Returns the cyan, magenta, and yellow as a tuple, in that order.
+	/// `Cmy::try_from` converts the tuple back.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Cmy;
+	///
+	/// let cmy = Cmy::new_unchecked(100, 0, 0);
+	/// assert_eq!(cmy.to_tuple(), (100, 0, 0));
+	/// ```
+	///
+	pub const fn to_tuple(self) -> (u8, u8, u8) {
+		(self.cyan, self.magenta, self.yellow)
+	}
+}
+
+impl fmt::Display for Cmy {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"cmy({}%, {}%, {}%)",
+			self.cyan, self.magenta, self.yellow
+		)
+	}
+}
+
+impl From<Rgb> for Cmy {
+	fn from(rgb: Rgb) -> Self {
+		let apply = |v: u8| ((1. - v as Float / 255.) * 100.).round() as u8;
+		Self::new_unchecked(apply(rgb.red), apply(rgb.green), apply(rgb.blue))
+	}
+}
+
+///
+/// Folds the key back into each of the other channels, so that a key of
+/// 0% leaves the cyan, magenta, and yellow values unchanged.
+///
+impl From<Cmyk> for Cmy {
+	fn from(cmyk: Cmyk) -> Self {
+		let key = cmyk.key as Float / 100.;
+		let apply = |v: u8| ((v as Float / 100. * (1. - key) + key) * 100.).round() as u8;
+
+		Self::new_unchecked(apply(cmyk.cyan), apply(cmyk.magenta), apply(cmyk.yellow))
+	}
+}
+
+impl TryFrom<(u8, u8, u8)> for Cmy {
+	type Error = Error;
+
+	fn try_from((cyan, magenta, yellow): (u8, u8, u8)) -> Result<Self, Self::Error> {
+		Cmy::new(cyan, magenta, yellow)
+	}
+}
+
+impl Color for Cmy {
+	fn to_rgb(self) -> Rgb {
+		let apply = |v: u8| ((1. - v as Float / 100.) * 255.).round() as u8;
+
+		let red = apply(self.cyan);
+		let green = apply(self.magenta);
+		let blue = apply(self.yellow);
+
+		Rgb { red, green, blue }
+	}
+
+	///
+	/// Extracts the key as the smallest of the cyan, magenta, and yellow
+	/// values, then rescales the remaining ink relative to it.
+	///
+	fn to_cmyk(self) -> Cmyk {
+		let key = self.cyan.min(self.magenta).min(self.yellow);
+
+		// Full coverage in every channel is pure black, and computing the
+		// other channels would divide by zero
+		if key == 100 {
+			return Cmyk::new_unchecked(0, 0, 0, 100);
+		}
+
+		let apply = |v: u8| ((v - key) as Float / (100 - key) as Float * 100.).round() as u8;
+		Cmyk::new_unchecked(
+			apply(self.cyan),
+			apply(self.magenta),
+			apply(self.yellow),
+			key,
+		)
+	}
+
+	fn to_hex_string(self) -> String {
+		self.to_rgb().to_hex_string()
+	}
+
+	fn to_hsl(self) -> Hsl {
+		self.to_rgb().to_hsl()
+	}
+
+	fn to_hsv(self) -> Hsv {
+		self.to_rgb().to_hsv()
+	}
+
+	fn to_hwb(self) -> Hwb {
+		self.to_rgb().to_hwb()
+	}
+
+	fn to_xyz(self) -> Xyz {
+		self.to_rgb().to_xyz()
+	}
+
+	fn to_lab(self) -> Lab {
+		self.to_rgb().to_lab()
+	}
+}
+
+impl Invert for Cmy {
+	fn invert(self) -> Cmy {
+		let apply = |channel: u8| 100u8.saturating_sub(channel);
+		Cmy::new_unchecked(apply(self.cyan), apply(self.magenta), apply(self.yellow))
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_to_string() {
+		let cmy = Cmy::new_unchecked(30, 50, 60);
+		assert_eq!(cmy.to_string(), String::from("cmy(30%, 50%, 60%)"));
+	}
+
+	#[test]
+	fn test_from_rgb() {
+		let cmy = Cmy::from(Rgb::new(107, 77, 61));
+		assert_eq!(cmy, Cmy::new_unchecked(58, 70, 76));
+		assert_eq!(cmy.to_cmyk(), Cmyk::new_unchecked(0, 29, 43, 58));
+		assert_eq!(
+			Rgb::new(107, 77, 61).to_cmyk(),
+			Cmyk::new_unchecked(0, 28, 43, 58)
+		);
+	}
+
+	#[test]
+	fn test_to_rgb() {
+		assert_eq!(
+			Cmy::new_unchecked(100, 0, 0).to_rgb(),
+			Rgb::new(0, 255, 255)
+		);
+		assert_eq!(
+			Cmy::new_unchecked(0, 0, 0).to_rgb(),
+			Rgb::new(255, 255, 255)
+		);
+		assert_eq!(
+			Cmy::new_unchecked(100, 100, 100).to_rgb(),
+			Rgb::new(0, 0, 0)
+		);
+	}
+
+	#[test]
+	fn test_cmyk_round_trip() {
+		for cmyk in [
+			Cmyk::new_unchecked(100, 0, 0, 0),
+			Cmyk::new_unchecked(0, 50, 100, 0),
+			Cmyk::new_unchecked(30, 0, 60, 0),
+			Cmyk::new_unchecked(0, 0, 0, 100),
+		]
+		.iter()
+		{
+			assert_eq!(Cmy::from(*cmyk).to_cmyk(), *cmyk);
+		}
+	}
+
+	#[test]
+	fn test_from_cmyk() {
+		assert_eq!(
+			Cmy::from(Cmyk::new_unchecked(30, 50, 60, 0)),
+			Cmy::new_unchecked(30, 50, 60)
+		);
+		assert_eq!(
+			Cmy::from(Cmyk::new_unchecked(0, 50, 100, 50)),
+			Cmy::new_unchecked(50, 75, 100)
+		);
+	}
+
+	#[test]
+	fn test_invert() {
+		let black = Cmy::new_unchecked(100, 100, 100);
+		let white = Cmy::new_unchecked(0, 0, 0);
+		assert_eq!(black.invert(), white);
+		assert_eq!(white.invert(), black);
+		assert_eq!(black.invert().to_rgb(), black.to_rgb().invert());
+	}
+
+	#[test]
+	fn test_invert_unchecked() {
+		assert_eq!(
+			Cmy::new_unchecked(150, 30, 255).invert(),
+			Cmy::new_unchecked(0, 70, 0)
+		);
+	}
+
+	#[should_panic]
+	#[test]
+	fn test_checked_cmy() {
+		Cmy::new(101, 0, 0).unwrap();
+	}
+
+	#[test]
+	fn test_try_from_tuple() {
+		assert_eq!(
+			Cmy::try_from((100, 0, 0)).unwrap(),
+			Cmy::new_unchecked(100, 0, 0)
+		);
+		assert!(matches!(
+			Cmy::try_from((100, 0, 101)),
+			Err(Error::PercentageOverflow)
+		));
+	}
+
+	#[test]
+	fn test_to_css() {
+		assert_eq!(
+			Cmy::new_unchecked(100, 0, 0).to_css(),
+			String::from("rgb(0, 255, 255)")
+		);
+	}
+
+	#[test]
+	fn test_to_tuple() {
+		let cmy = Cmy::new_unchecked(100, 0, 0);
+		assert_eq!(Cmy::try_from(cmy.to_tuple()).unwrap(), cmy);
+	}
+}
@@ -1,10 +1,11 @@
-use crate::{Color, Error, Hsl, Rgb};
-use std::fmt;
+use crate::prelude::*;
+use core::{convert::TryFrom, fmt};
 
 ///
 /// A representation of the CMYK (cyan, magenta, yellow, key) color format.
 ///
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Cmyk {
 	/// Cyan value (percentage)
 	pub cyan: u8,
@@ -58,7 +59,7 @@ impl Cmyk {
 	/// let cyan = Cmyk::new_unchecked(100, 0, 0, 0);
 	/// ```
 	///
-	pub fn new_unchecked(cyan: u8, magenta: u8, yellow: u8, key: u8) -> Self {
+	pub const fn new_unchecked(cyan: u8, magenta: u8, yellow: u8, key: u8) -> Self {
 		Self {
 			cyan,
 			magenta,
@@ -66,6 +67,160 @@ impl Cmyk {
 			key,
 		}
 	}
+
+	///
+	/// See [`Cmyk::new`](self::Cmyk::new). Instead of returning an [`Error`],
+	/// any value larger than 100 is clamped to 100, so a valid Cmyk object is
+	/// always returned.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Cmyk;
+	/// let cyan = Cmyk::new_clamped(150, 0, 0, 0);
+	/// assert_eq!(cyan, Cmyk::new_unchecked(100, 0, 0, 0));
+	/// ```
+	///
+	pub fn new_clamped(cyan: u8, magenta: u8, yellow: u8, key: u8) -> Self {
+		Self::new_unchecked(
+			cyan.min(100),
+			magenta.min(100),
+			yellow.min(100),
+			key.min(100),
+		)
+	}
+
+	///
+	/// Returns the cyan, magenta, yellow, and key as a tuple, in that order.
+	/// `Cmyk::try_from` converts the tuple back.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Cmyk;
+	///
+	/// let cmyk = Cmyk::new_unchecked(50, 17, 0, 76);
+	/// assert_eq!(cmyk.to_tuple(), (50, 17, 0, 76));
+	/// ```
+	///
+	pub const fn to_tuple(self) -> (u8, u8, u8, u8) {
+		(self.cyan, self.magenta, self.yellow, self.key)
+	}
+
+	///
+	/// Returns a [`CmykBuilder`] for constructing a Cmyk object one field at a
+	/// time. Unset fields default to 0.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Cmyk;
+	/// let cyan = Cmyk::builder().cyan(100).build()?;
+	/// # Ok::<(), color_conv::Error>(())
+	/// ```
+	///
+	pub fn builder() -> CmykBuilder {
+		CmykBuilder::default()
+	}
+}
+
+///
+/// A builder for [`Cmyk`], created by [`Cmyk::builder`](self::Cmyk::builder).
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, Default)]
+pub struct CmykBuilder {
+	cyan: u8,
+	magenta: u8,
+	yellow: u8,
+	key: u8,
+}
+
+impl CmykBuilder {
+	///
+	/// Sets the cyan value of the color.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Cmyk;
+	/// let builder = Cmyk::builder().cyan(50);
+	/// ```
+	///
+	pub fn cyan(mut self, cyan: u8) -> Self {
+		self.cyan = cyan;
+		self
+	}
+
+	///
+	/// Sets the magenta value of the color.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Cmyk;
+	/// let builder = Cmyk::builder().magenta(50);
+	/// ```
+	///
+	pub fn magenta(mut self, magenta: u8) -> Self {
+		self.magenta = magenta;
+		self
+	}
+
+	///
+	/// Sets the yellow value of the color.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Cmyk;
+	/// let builder = Cmyk::builder().yellow(50);
+	/// ```
+	///
+	pub fn yellow(mut self, yellow: u8) -> Self {
+		self.yellow = yellow;
+		self
+	}
+
+	///
+	/// Sets the key value of the color.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Cmyk;
+	/// let builder = Cmyk::builder().key(50);
+	/// ```
+	///
+	pub fn key(mut self, key: u8) -> Self {
+		self.key = key;
+		self
+	}
+
+	///
+	/// Returns a Result containing the built Cmyk object. Performs the same
+	/// validation as [`Cmyk::new`](self::Cmyk::new).
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Cmyk;
+	/// let cyan = Cmyk::builder().cyan(100).build()?;
+	/// assert_eq!(cyan, Cmyk::new(100, 0, 0, 0)?);
+	/// # Ok::<(), color_conv::Error>(())
+	/// ```
+	///
+	pub fn build(self) -> Result<Cmyk, Error> {
+		Cmyk::new(self.cyan, self.magenta, self.yellow, self.key)
+	}
+}
+
+///
+/// Defaults to no ink at all, `cmyk(0%, 0%, 0%, 0%)`, which is white.
+///
+impl Default for Cmyk {
+	fn default() -> Self {
+		Self::new_unchecked(0, 0, 0, 0)
+	}
 }
 
 impl fmt::Display for Cmyk {
@@ -78,10 +233,25 @@ impl fmt::Display for Cmyk {
 	}
 }
 
+impl From<Rgb> for Cmyk {
+	fn from(rgb: Rgb) -> Self {
+		rgb.to_cmyk()
+	}
+}
+
+impl TryFrom<(u8, u8, u8, u8)> for Cmyk {
+	type Error = Error;
+
+	fn try_from((cyan, magenta, yellow, key): (u8, u8, u8, u8)) -> Result<Self, Self::Error> {
+		Cmyk::new(cyan, magenta, yellow, key)
+	}
+}
+
 impl Color for Cmyk {
 	fn to_rgb(self) -> Rgb {
-		let apply =
-			|v| (255. * (1f64 - v as f64 / 100.) * (1. - self.key as f64 / 100.)).round() as u8;
+		let apply = |v: u8| {
+			(255. * (1. - v as Float / 100.) * (1. - self.key as Float / 100.)).round() as u8
+		};
 
 		let red = apply(self.cyan);
 		let green = apply(self.magenta);
@@ -101,6 +271,28 @@ impl Color for Cmyk {
 	fn to_hsl(self) -> Hsl {
 		self.to_rgb().to_hsl()
 	}
+
+	fn to_hsv(self) -> Hsv {
+		self.to_rgb().to_hsv()
+	}
+
+	fn to_xyz(self) -> Xyz {
+		self.to_rgb().to_xyz()
+	}
+
+	fn to_lab(self) -> Lab {
+		self.to_rgb().to_lab()
+	}
+
+	fn to_hwb(self) -> Hwb {
+		self.to_rgb().to_hwb()
+	}
+}
+
+impl Invert for Cmyk {
+	fn invert(self) -> Cmyk {
+		self.to_rgb().invert().to_cmyk()
+	}
 }
 
 #[cfg(test)]
@@ -130,4 +322,120 @@ mod test {
 	fn test_checked_cmyk() {
 		Cmyk::new(255, 255, 255, 255).unwrap();
 	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn test_serde() {
+		let cmyk = Cmyk::new_unchecked(100, 0, 0, 0);
+		let json = serde_json::to_string(&cmyk).unwrap();
+		assert_eq!(json, r#"{"cyan":100,"magenta":0,"yellow":0,"key":0}"#);
+		assert_eq!(serde_json::from_str::<Cmyk>(&json).unwrap(), cmyk);
+	}
+
+	#[test]
+	fn test_invert() {
+		let black = Cmyk::new_unchecked(0, 0, 0, 100);
+		let white = Cmyk::new_unchecked(0, 0, 0, 0);
+		assert_eq!(black.invert(), white);
+		assert_eq!(white.invert(), black);
+		assert_eq!(
+			Cmyk::new_unchecked(100, 0, 0, 0).invert(),
+			Cmyk::new_unchecked(0, 100, 100, 0)
+		);
+	}
+
+	#[test]
+	fn test_from_rgb() {
+		let cmyk: Cmyk = Rgb::new(0, 255, 255).into();
+		assert_eq!(cmyk, Cmyk::new_unchecked(100, 0, 0, 0));
+	}
+
+	#[test]
+	fn test_try_from_tuple() {
+		assert_eq!(
+			Cmyk::try_from((100, 0, 0, 0)).unwrap(),
+			Cmyk::new_unchecked(100, 0, 0, 0)
+		);
+		assert!(matches!(
+			Cmyk::try_from((101, 0, 0, 0)),
+			Err(Error::PercentageOverflow)
+		));
+	}
+
+	#[test]
+	fn test_builder() {
+		let cmyk = Cmyk::builder()
+			.cyan(30)
+			.magenta(50)
+			.yellow(60)
+			.key(40)
+			.build()
+			.unwrap();
+		assert_eq!(cmyk, Cmyk::new_unchecked(30, 50, 60, 40));
+	}
+
+	#[test]
+	fn test_builder_partial() {
+		let cmyk = Cmyk::builder().magenta(50).build().unwrap();
+		assert_eq!(cmyk, Cmyk::new_unchecked(0, 50, 0, 0));
+		assert_eq!(
+			Cmyk::builder().build().unwrap(),
+			Cmyk::new_unchecked(0, 0, 0, 0)
+		);
+	}
+
+	#[test]
+	fn test_builder_checked() {
+		let result = Cmyk::builder().key(101).build();
+		assert!(matches!(result, Err(Error::PercentageOverflow)));
+	}
+
+	#[test]
+	fn test_default() {
+		assert_eq!(Cmyk::default(), Cmyk::new_unchecked(0, 0, 0, 0));
+		assert_eq!(Cmyk::default().to_rgb(), Rgb::new(255, 255, 255));
+	}
+
+	#[test]
+	fn test_new_clamped() {
+		assert_eq!(
+			Cmyk::new_clamped(150, 101, 100, 255),
+			Cmyk::new_unchecked(100, 100, 100, 100)
+		);
+		assert_eq!(
+			Cmyk::new_clamped(30, 50, 60, 40),
+			Cmyk::new_unchecked(30, 50, 60, 40)
+		);
+	}
+
+	#[test]
+	fn test_to_css() {
+		assert_eq!(
+			Cmyk::new_unchecked(100, 0, 0, 0).to_css(),
+			String::from("rgb(0, 255, 255)")
+		);
+	}
+
+	#[test]
+	fn test_to_rgb_expected_values() {
+		for &(cmyk, rgb) in [
+			(Cmyk::new_unchecked(0, 0, 0, 0), Rgb::new(255, 255, 255)),
+			(Cmyk::new_unchecked(0, 0, 0, 100), Rgb::new(0, 0, 0)),
+			(Cmyk::new_unchecked(100, 0, 0, 0), Rgb::new(0, 255, 255)),
+			(Cmyk::new_unchecked(30, 50, 60, 40), Rgb::new(107, 77, 61)),
+			(Cmyk::new_unchecked(50, 0, 0, 50), Rgb::new(64, 128, 128)),
+			(Cmyk::new_unchecked(0, 0, 0, 50), Rgb::new(128, 128, 128)),
+			(Cmyk::new_unchecked(88, 17, 0, 29), Rgb::new(22, 150, 181)),
+		]
+		.iter()
+		{
+			assert_eq!(cmyk.to_rgb(), rgb);
+		}
+	}
+
+	#[test]
+	fn test_to_tuple() {
+		let cmyk = Cmyk::new_unchecked(50, 17, 0, 76);
+		assert_eq!(Cmyk::try_from(cmyk.to_tuple()).unwrap(), cmyk);
+	}
 }
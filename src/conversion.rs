@@ -0,0 +1,181 @@
+use crate::prelude::*;
+use core::{fmt, str::FromStr};
+
+///
+/// A conversion target that can be chosen at runtime, such as from a
+/// command-line flag, for use with [`Color::convert_to`].
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum Format {
+	/// Convert to [`Rgb`]
+	Rgb,
+	/// Convert to [`Cmyk`]
+	Cmyk,
+	/// Convert to [`Hsl`]
+	Hsl,
+	/// Convert to a hex string, as in [`Color::to_hex_string`]
+	Hex,
+}
+
+impl FromStr for Format {
+	type Err = Error;
+
+	///
+	/// Parses the name of a format, ignoring case and surrounding whitespace.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Format;
+	///
+	/// assert_eq!("hsl".parse::<Format>()?, Format::Hsl);
+	/// assert_eq!("HEX".parse::<Format>()?, Format::Hex);
+	/// # Ok::<(), color_conv::Error>(())
+	/// ```
+	///
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s.trim().to_ascii_lowercase().as_str() {
+			"rgb" => Ok(Format::Rgb),
+			"cmyk" => Ok(Format::Cmyk),
+			"hsl" => Ok(Format::Hsl),
+			"hex" => Ok(Format::Hex),
+			_ => Err(Error::ParseError(format!("unknown format `{}`", s))),
+		}
+	}
+}
+
+///
+/// A color converted with [`Color::convert_to`], carrying the converted
+/// value. Its [`Display`](core::fmt::Display) implementation forwards to
+/// that of the value, so `to_string` gives the formatted color.
+///
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub enum FormattedColor {
+	/// A color converted to [`Rgb`]
+	Rgb(Rgb),
+	/// A color converted to [`Cmyk`]
+	Cmyk(Cmyk),
+	/// A color converted to [`Hsl`]
+	Hsl(Hsl),
+	/// A color converted to a hex string
+	Hex(String),
+}
+
+impl FormattedColor {
+	///
+	/// Returns the [`Format`] the color was converted to.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::{Color, Format, Rgb};
+	///
+	/// let converted = Rgb::new(0, 255, 255).convert_to(Format::Cmyk);
+	/// assert_eq!(converted.format(), Format::Cmyk);
+	/// ```
+	///
+	pub fn format(&self) -> Format {
+		match self {
+			FormattedColor::Rgb(_) => Format::Rgb,
+			FormattedColor::Cmyk(_) => Format::Cmyk,
+			FormattedColor::Hsl(_) => Format::Hsl,
+			FormattedColor::Hex(_) => Format::Hex,
+		}
+	}
+}
+
+impl fmt::Display for FormattedColor {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			FormattedColor::Rgb(rgb) => fmt::Display::fmt(rgb, f),
+			FormattedColor::Cmyk(cmyk) => fmt::Display::fmt(cmyk, f),
+			FormattedColor::Hsl(hsl) => fmt::Display::fmt(hsl, f),
+			FormattedColor::Hex(hex) => fmt::Display::fmt(hex, f),
+		}
+	}
+}
+
+///
+/// A color converted to every [`Format`] at once, as returned by
+/// [`Color::to_all`].
+///
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
+pub struct AllFormats {
+	/// The color as [`Rgb`]
+	pub rgb: Rgb,
+	/// The color as [`Cmyk`]
+	pub cmyk: Cmyk,
+	/// The color as [`Hsl`]
+	pub hsl: Hsl,
+	/// The color as a hex string, as in [`Color::to_hex_string`]
+	pub hex: String,
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_convert_to_every_format() {
+		let cyan = Hsl::new_unchecked(180, 100, 50);
+
+		let rgb = cyan.convert_to(Format::Rgb);
+		assert_eq!(rgb, FormattedColor::Rgb(Rgb::new(0, 255, 255)));
+		assert_eq!(rgb.to_string(), String::from("rgb(0, 255, 255)"));
+
+		let cmyk = cyan.convert_to(Format::Cmyk);
+		assert_eq!(
+			cmyk,
+			FormattedColor::Cmyk(Cmyk::new_unchecked(100, 0, 0, 0))
+		);
+		assert_eq!(cmyk.to_string(), String::from("cmyk(100%, 0%, 0%, 0%)"));
+
+		let hsl = cyan.convert_to(Format::Hsl);
+		assert_eq!(hsl, FormattedColor::Hsl(cyan));
+		assert_eq!(hsl.to_string(), String::from("hsl(180°, 100%, 50%)"));
+
+		let hex = cyan.convert_to(Format::Hex);
+		assert_eq!(hex, FormattedColor::Hex(String::from("#00ffff")));
+		assert_eq!(hex.to_string(), String::from("#00ffff"));
+	}
+
+	#[test]
+	fn test_format() {
+		for &format in [Format::Rgb, Format::Cmyk, Format::Hsl, Format::Hex].iter() {
+			assert_eq!(Rgb::new(30, 50, 60).convert_to(format).format(), format);
+		}
+	}
+
+	#[test]
+	fn test_format_from_str() {
+		assert_eq!("rgb".parse::<Format>().unwrap(), Format::Rgb);
+		assert_eq!(" Cmyk ".parse::<Format>().unwrap(), Format::Cmyk);
+		assert!(matches!("hsv".parse::<Format>(), Err(Error::ParseError(_))));
+	}
+
+	#[test]
+	fn test_to_all() {
+		let all = Cmyk::new_unchecked(0, 50, 100, 0).to_all();
+
+		assert_eq!(
+			all,
+			AllFormats {
+				rgb: Rgb::new(255, 128, 0),
+				cmyk: Cmyk::new_unchecked(0, 50, 100, 0),
+				hsl: Hsl::new_unchecked(30, 100, 50),
+				hex: String::from("#ff8000"),
+			}
+		);
+	}
+
+	#[test]
+	fn test_to_all_matches_individual_conversions() {
+		let hsl = Hsl::new_unchecked(210, 40, 35);
+		let all = hsl.to_all();
+
+		assert_eq!(all.rgb, hsl.to_rgb());
+		assert_eq!(all.cmyk, hsl.to_cmyk());
+		assert_eq!(all.hsl, hsl.to_rgb().to_hsl());
+		assert_eq!(all.hex, hsl.to_hex_string());
+	}
+}
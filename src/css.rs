@@ -0,0 +1,35 @@
+use crate::prelude::*;
+
+///
+/// Formats an alpha value normalized to `0.0`-`1.0` compactly for CSS,
+/// rounding it to three decimal places and trimming any trailing zeros, so
+/// `1.0` becomes `1` and `0.500` becomes `0.5`.
+///
+pub(crate) fn alpha(alpha: f64) -> String {
+	let rounded = format!("{:.3}", alpha);
+	let trimmed = rounded.trim_end_matches('0').trim_end_matches('.');
+
+	// Tiny negative values round to `-0.000`
+	if trimmed == "-0" {
+		String::from("0")
+	} else {
+		String::from(trimmed)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_alpha() {
+		assert_eq!(alpha(1.), String::from("1"));
+		assert_eq!(alpha(0.5), String::from("0.5"));
+		assert_eq!(alpha(0.333), String::from("0.333"));
+		assert_eq!(alpha(1. / 3.), String::from("0.333"));
+		assert_eq!(alpha(0.1 + 0.2), String::from("0.3"));
+		assert_eq!(alpha(0.), String::from("0"));
+		assert_eq!(alpha(0.0004), String::from("0"));
+		assert_eq!(alpha(-0.0004), String::from("0"));
+	}
+}
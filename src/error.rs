@@ -0,0 +1,151 @@
+use alloc::string::String;
+use thiserror::Error as ThisError;
+
+#[derive(ThisError, Debug)]
+#[non_exhaustive]
+///
+/// Crate-wide Error type.
+///
+pub enum Error {
+	///
+	/// Occurs when a parameter representing a percentage value is greater than
+	/// 100. This error can be thrown by [`Cmyk::new`](crate::Cmyk::new),
+	/// [`Hsl::new`](crate::Hsl::new), [`Hsv::new`](crate::Hsv::new), or
+	/// [`Hwb::new`](crate::Hwb::new), all of which perform this check.
+	///
+	#[error("Percentage overflow: value is larger than 100!")]
+	PercentageOverflow,
+	///
+	/// Occurs when a parameter representing a degree value is greater than 360.
+	/// This error can be thrown by [`Hsl::new`](crate::Hsl::new),
+	/// [`Hsv::new`](crate::Hsv::new), or [`Hwb::new`](crate::Hwb::new), all of
+	/// which perform this check.
+	///
+	#[error("Degree overflow: value is larger than 360!")]
+	DegreeOverflow,
+	///
+	/// Occurs when a string cannot be parsed into a color, either because it
+	/// is malformed or because one of its values is out of range. This error
+	/// can be thrown by the [`FromStr`](core::str::FromStr) implementations of
+	/// [`Rgb`](crate::Rgb) and [`Hsla`](crate::Hsla).
+	///
+	#[error("Parse error: {0}")]
+	ParseError(String),
+	///
+	/// Occurs when a string is not a valid hex color, either because it
+	/// contains non-hexadecimal digits or because it has the wrong length.
+	/// This error can be thrown by
+	/// [`Rgb::from_hex_str`](crate::Rgb::from_hex_str) and
+	/// [`Rgba::from_hex_str`](crate::Rgba::from_hex_str).
+	///
+	#[error("Invalid hex: string is not a valid hex color!")]
+	InvalidHex,
+	///
+	/// Occurs when a parameter representing an alpha value is not between 0.0
+	/// and 1.0. This error can be thrown by [`Hsla::new`](crate::Hsla::new)
+	/// and [`Rgba::try_from_rgb`](crate::Rgba::try_from_rgb).
+	///
+	#[error("Alpha overflow: value is not between 0.0 and 1.0!")]
+	AlphaOverflow,
+	///
+	/// Occurs when a parameter representing an 8-bit color channel is not
+	/// between 0 and 255. This error can be thrown by
+	/// [`Rgb::try_new`](crate::Rgb::try_new).
+	///
+	#[error("Channel overflow: value is not between 0 and 255!")]
+	ChannelOverflow,
+}
+
+impl Error {
+	///
+	/// Returns whether this is an [`Error::PercentageOverflow`]. Since
+	/// [`Error`] is `#[non_exhaustive]`, this allows branching on the kind of
+	/// error without a wildcard match arm.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	///
+	/// let error = Hsl::new(180, 101, 50).unwrap_err();
+	/// assert!(error.is_percentage_overflow());
+	/// ```
+	///
+	pub fn is_percentage_overflow(&self) -> bool {
+		matches!(self, Error::PercentageOverflow)
+	}
+
+	///
+	/// Returns whether this is an [`Error::DegreeOverflow`]. See
+	/// [`Error::is_percentage_overflow`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	///
+	/// let error = Hsl::new(361, 100, 50).unwrap_err();
+	/// assert!(error.is_degree_overflow());
+	/// ```
+	///
+	pub fn is_degree_overflow(&self) -> bool {
+		matches!(self, Error::DegreeOverflow)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use alloc::string::ToString;
+
+	#[test]
+	fn test_to_string() {
+		assert_eq!(
+			Error::PercentageOverflow.to_string(),
+			String::from("Percentage overflow: value is larger than 100!")
+		);
+		assert_eq!(
+			Error::DegreeOverflow.to_string(),
+			String::from("Degree overflow: value is larger than 360!")
+		);
+		assert_eq!(
+			Error::ParseError(String::from("bad input")).to_string(),
+			String::from("Parse error: bad input")
+		);
+		assert_eq!(
+			Error::InvalidHex.to_string(),
+			String::from("Invalid hex: string is not a valid hex color!")
+		);
+		assert_eq!(
+			Error::AlphaOverflow.to_string(),
+			String::from("Alpha overflow: value is not between 0.0 and 1.0!")
+		);
+		assert_eq!(
+			Error::ChannelOverflow.to_string(),
+			String::from("Channel overflow: value is not between 0 and 255!")
+		);
+	}
+
+	#[test]
+	fn test_predicates() {
+		let percentage = crate::Cmyk::new(101, 0, 0, 0).unwrap_err();
+		assert!(percentage.is_percentage_overflow());
+		assert!(!percentage.is_degree_overflow());
+
+		let degree = crate::Hsv::new(400, 0, 0).unwrap_err();
+		assert!(degree.is_degree_overflow());
+		assert!(!degree.is_percentage_overflow());
+
+		for error in [
+			Error::ParseError(String::from("bad input")),
+			Error::InvalidHex,
+			Error::AlphaOverflow,
+			Error::ChannelOverflow,
+		]
+		.iter()
+		{
+			assert!(!error.is_percentage_overflow());
+			assert!(!error.is_degree_overflow());
+		}
+	}
+}
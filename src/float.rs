@@ -0,0 +1,125 @@
+//!
+//! Floating-point math for `no_std` builds. Without `std`, the inherent
+//! `f32`/`f64` methods that rely on the platform's math library are not
+//! available, so [`FloatExt`] provides them on top of [`libm`].
+//!
+
+#[allow(dead_code)]
+pub(crate) trait FloatExt: Sized {
+	fn round(self) -> Self;
+	fn powf(self, n: Self) -> Self;
+	fn powi(self, n: i32) -> Self;
+	fn sqrt(self) -> Self;
+	fn cbrt(self) -> Self;
+	fn ln(self) -> Self;
+	fn exp(self) -> Self;
+	fn sin(self) -> Self;
+	fn cos(self) -> Self;
+	fn atan2(self, other: Self) -> Self;
+	fn hypot(self, other: Self) -> Self;
+	fn rem_euclid(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_float_ext {
+	($ty:ty, $round:ident, $pow:ident, $sqrt:ident, $cbrt:ident, $ln:ident, $exp:ident,
+	 $sin:ident, $cos:ident, $atan2:ident, $hypot:ident) => {
+		impl FloatExt for $ty {
+			fn round(self) -> Self {
+				libm::$round(self)
+			}
+
+			fn powf(self, n: Self) -> Self {
+				libm::$pow(self, n)
+			}
+
+			fn powi(self, n: i32) -> Self {
+				libm::$pow(self, n as $ty)
+			}
+
+			fn sqrt(self) -> Self {
+				libm::$sqrt(self)
+			}
+
+			fn cbrt(self) -> Self {
+				libm::$cbrt(self)
+			}
+
+			fn ln(self) -> Self {
+				libm::$ln(self)
+			}
+
+			fn exp(self) -> Self {
+				libm::$exp(self)
+			}
+
+			fn sin(self) -> Self {
+				libm::$sin(self)
+			}
+
+			fn cos(self) -> Self {
+				libm::$cos(self)
+			}
+
+			fn atan2(self, other: Self) -> Self {
+				libm::$atan2(self, other)
+			}
+
+			fn hypot(self, other: Self) -> Self {
+				libm::$hypot(self, other)
+			}
+
+			fn rem_euclid(self, rhs: Self) -> Self {
+				let r = self % rhs;
+				if r < 0. {
+					r + rhs.abs()
+				} else {
+					r
+				}
+			}
+		}
+	};
+}
+
+impl_float_ext!(f64, round, pow, sqrt, cbrt, log, exp, sin, cos, atan2, hypot);
+impl_float_ext!(f32, roundf, powf, sqrtf, cbrtf, logf, expf, sinf, cosf, atan2f, hypotf);
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_round() {
+		assert_eq!(FloatExt::round(2.5_f64), 3.);
+		assert_eq!(FloatExt::round(-2.5_f64), -3.);
+		assert_eq!(FloatExt::round(2.4_f32), 2.);
+	}
+
+	#[test]
+	fn test_powi() {
+		assert_eq!(FloatExt::powi(3_f64, 2), 9.);
+		assert_eq!(FloatExt::powi(2_f32, -1), 0.5);
+	}
+
+	#[test]
+	fn test_roots_and_exponents() {
+		assert_eq!(FloatExt::sqrt(16_f64), 4.);
+		assert_eq!(FloatExt::cbrt(27_f64), 3.);
+		assert_eq!(FloatExt::powf(4_f32, 0.5), 2.);
+		assert!((FloatExt::ln(FloatExt::exp(2_f64)) - 2.).abs() < 1e-12);
+	}
+
+	#[test]
+	fn test_rem_euclid() {
+		assert_eq!(FloatExt::rem_euclid(-30_f64, 360.), 330.);
+		assert_eq!(FloatExt::rem_euclid(390_f64, 360.), 30.);
+		assert_eq!(FloatExt::rem_euclid(-30_f32, 360.), 330.);
+	}
+
+	#[test]
+	fn test_trig() {
+		let angle = FloatExt::atan2(1_f64, 1.);
+		assert!((angle - core::f64::consts::FRAC_PI_4).abs() < 1e-12);
+		assert!((FloatExt::sin(angle) - FloatExt::cos(angle)).abs() < 1e-12);
+		assert_eq!(FloatExt::hypot(3_f64, 4.), 5.);
+	}
+}
@@ -1,10 +1,11 @@
-use crate::{Cmyk, Color, Error, Rgb};
-use std::fmt;
+use crate::{parse, prelude::*};
+use core::{convert::TryFrom, fmt, str::FromStr};
 
 ///
 /// A representation of the HSL (cyan, magenta, yellow, key) color format.
 ///
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Hsl {
 	/// Hue value (in degrees)
 	pub hue: u16,
@@ -60,13 +61,787 @@ impl Hsl {
 	/// let cyan = Hsl::new_unchecked(180, 100, 50);
 	/// ```
 	///
-	pub fn new_unchecked(hue: u16, saturation: u8, lightness: u8) -> Self {
+	pub const fn new_unchecked(hue: u16, saturation: u8, lightness: u8) -> Self {
 		Self {
 			hue,
 			saturation,
 			lightness,
 		}
 	}
+
+	///
+	/// See [`Hsl::new`](self::Hsl::new). Instead of returning an [`Error`],
+	/// the saturation and lightness are clamped to 100 and the hue wraps
+	/// around into the range `[0, 360)`, so a valid Hsl object is always
+	/// returned.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	/// let hsl = Hsl::new_clamped(400, 150, 50);
+	/// assert_eq!(hsl, Hsl::new_unchecked(40, 100, 50));
+	/// ```
+	///
+	pub fn new_clamped(hue: u16, saturation: u8, lightness: u8) -> Self {
+		Self::new_unchecked(hue % 360, saturation.min(100), lightness.min(100))
+	}
+
+	///
+	/// See [`Hsl::new`](self::Hsl::new). Instead of returning an
+	/// [`Error::DegreeOverflow`], the hue wraps around into the range
+	/// `[0, 360)`, so `365` becomes `5`. The saturation and lightness are
+	/// still validated.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	///
+	/// let hsl = Hsl::new_wrapping(365, 100, 50)?;
+	/// assert_eq!(hsl, Hsl::new_unchecked(5, 100, 50));
+	///
+	/// assert!(Hsl::new_wrapping(365, 101, 50).is_err());
+	/// # Ok::<(), color_conv::Error>(())
+	/// ```
+	///
+	pub fn new_wrapping(hue: u16, saturation: u8, lightness: u8) -> Result<Self, Error> {
+		Self::new(hue % 360, saturation, lightness)
+	}
+
+	///
+	/// Returns the hue, saturation, and lightness as a tuple, in that order.
+	/// `Hsl::try_from` converts the tuple back.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	///
+	/// let hsl = Hsl::new_unchecked(180, 100, 50);
+	/// assert_eq!(hsl.to_tuple(), (180, 100, 50));
+	/// ```
+	///
+	pub const fn to_tuple(self) -> (u16, u8, u8) {
+		(self.hue, self.saturation, self.lightness)
+	}
+
+	///
+	/// Returns a copy of the color with the hue replaced, performing the
+	/// same validation as [`Hsl::new`](self::Hsl::new).
+	///
+	/// # Arguments
+	///
+	/// * `hue` - the new hue value
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	///
+	/// let hsl = Hsl::new_unchecked(180, 100, 50).with_hue(90)?;
+	/// assert_eq!(hsl, Hsl::new_unchecked(90, 100, 50));
+	/// # Ok::<(), color_conv::Error>(())
+	/// ```
+	///
+	pub fn with_hue(self, hue: u16) -> Result<Hsl, Error> {
+		Hsl::new(hue, self.saturation, self.lightness)
+	}
+
+	///
+	/// Returns a copy of the color with the saturation replaced, performing the
+	/// same validation as [`Hsl::new`](self::Hsl::new).
+	///
+	/// # Arguments
+	///
+	/// * `saturation` - the new saturation value
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	///
+	/// let hsl = Hsl::new_unchecked(180, 100, 50).with_saturation(50)?;
+	/// assert_eq!(hsl, Hsl::new_unchecked(180, 50, 50));
+	/// # Ok::<(), color_conv::Error>(())
+	/// ```
+	///
+	pub fn with_saturation(self, saturation: u8) -> Result<Hsl, Error> {
+		Hsl::new(self.hue, saturation, self.lightness)
+	}
+
+	///
+	/// Returns a copy of the color with the lightness replaced, performing the
+	/// same validation as [`Hsl::new`](self::Hsl::new).
+	///
+	/// # Arguments
+	///
+	/// * `lightness` - the new lightness value
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	///
+	/// let hsl = Hsl::new_unchecked(180, 100, 50).with_lightness(25)?;
+	/// assert_eq!(hsl, Hsl::new_unchecked(180, 100, 25));
+	/// # Ok::<(), color_conv::Error>(())
+	/// ```
+	///
+	pub fn with_lightness(self, lightness: u8) -> Result<Hsl, Error> {
+		Hsl::new(self.hue, self.saturation, lightness)
+	}
+
+	///
+	/// Replaces the hue in place, performing the same validation as
+	/// [`Hsl::new`](self::Hsl::new). The color is left unchanged if the hue
+	/// is invalid.
+	///
+	/// # Arguments
+	///
+	/// * `hue` - the new hue value
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	///
+	/// let mut hsl = Hsl::new_unchecked(180, 100, 50);
+	/// hsl.set_hue(90)?;
+	/// assert_eq!(hsl, Hsl::new_unchecked(90, 100, 50));
+	/// # Ok::<(), color_conv::Error>(())
+	/// ```
+	///
+	pub fn set_hue(&mut self, hue: u16) -> Result<(), Error> {
+		*self = self.with_hue(hue)?;
+		Ok(())
+	}
+
+	///
+	/// Replaces the saturation in place, performing the same validation as
+	/// [`Hsl::new`](self::Hsl::new). The color is left unchanged if the
+	/// saturation is invalid.
+	///
+	/// # Arguments
+	///
+	/// * `saturation` - the new saturation value
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	///
+	/// let mut hsl = Hsl::new_unchecked(180, 100, 50);
+	/// hsl.set_saturation(50)?;
+	/// assert_eq!(hsl, Hsl::new_unchecked(180, 50, 50));
+	/// # Ok::<(), color_conv::Error>(())
+	/// ```
+	///
+	pub fn set_saturation(&mut self, saturation: u8) -> Result<(), Error> {
+		*self = self.with_saturation(saturation)?;
+		Ok(())
+	}
+
+	///
+	/// Replaces the lightness in place, performing the same validation as
+	/// [`Hsl::new`](self::Hsl::new). The color is left unchanged if the
+	/// lightness is invalid.
+	///
+	/// # Arguments
+	///
+	/// * `lightness` - the new lightness value
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	///
+	/// let mut hsl = Hsl::new_unchecked(180, 100, 50);
+	/// hsl.set_lightness(25)?;
+	/// assert_eq!(hsl, Hsl::new_unchecked(180, 100, 25));
+	/// # Ok::<(), color_conv::Error>(())
+	/// ```
+	///
+	pub fn set_lightness(&mut self, lightness: u8) -> Result<(), Error> {
+		*self = self.with_lightness(lightness)?;
+		Ok(())
+	}
+
+	///
+	/// Returns a lighter color by adding `amount` to the lightness,
+	/// saturating at 100.
+	///
+	/// # Arguments
+	///
+	/// * `amount` - the percentage to add to the lightness
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	///
+	/// let hsl = Hsl::new_unchecked(180, 100, 50);
+	/// assert_eq!(hsl.lighten(20), Hsl::new_unchecked(180, 100, 70));
+	/// assert_eq!(hsl.lighten(80), Hsl::new_unchecked(180, 100, 100));
+	/// ```
+	///
+	pub fn lighten(self, amount: u8) -> Hsl {
+		Self::new_unchecked(
+			self.hue,
+			self.saturation,
+			self.lightness.saturating_add(amount).min(100),
+		)
+	}
+
+	///
+	/// Returns a darker color by subtracting `amount` from the lightness,
+	/// saturating at 0.
+	///
+	/// # Arguments
+	///
+	/// * `amount` - the percentage to subtract from the lightness
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	///
+	/// let hsl = Hsl::new_unchecked(180, 100, 50);
+	/// assert_eq!(hsl.darken(20), Hsl::new_unchecked(180, 100, 30));
+	/// assert_eq!(hsl.darken(80), Hsl::new_unchecked(180, 100, 0));
+	/// ```
+	///
+	pub fn darken(self, amount: u8) -> Hsl {
+		Self::new_unchecked(
+			self.hue,
+			self.saturation,
+			self.lightness.saturating_sub(amount),
+		)
+	}
+
+	///
+	/// Returns a more saturated color by adding `amount` to the saturation,
+	/// saturating at 100.
+	///
+	/// # Arguments
+	///
+	/// * `amount` - the percentage to add to the saturation
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	///
+	/// let hsl = Hsl::new_unchecked(180, 50, 50);
+	/// assert_eq!(hsl.saturate(20), Hsl::new_unchecked(180, 70, 50));
+	/// ```
+	///
+	pub fn saturate(self, amount: u8) -> Hsl {
+		Self::new_unchecked(
+			self.hue,
+			self.saturation.saturating_add(amount).min(100),
+			self.lightness,
+		)
+	}
+
+	///
+	/// Returns a less saturated color by subtracting `amount` from the
+	/// saturation, saturating at 0.
+	///
+	/// # Arguments
+	///
+	/// * `amount` - the percentage to subtract from the saturation
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	///
+	/// let hsl = Hsl::new_unchecked(180, 50, 50);
+	/// assert_eq!(hsl.desaturate(20), Hsl::new_unchecked(180, 30, 50));
+	/// ```
+	///
+	pub fn desaturate(self, amount: u8) -> Hsl {
+		Self::new_unchecked(
+			self.hue,
+			self.saturation.saturating_sub(amount),
+			self.lightness,
+		)
+	}
+
+	///
+	/// Returns a color with the lightness multiplied by `factor`, rounded
+	/// and clamped to the range 0 to 100. Factors above 1.0 lighten the
+	/// color and factors below 1.0 darken it.
+	///
+	/// # Arguments
+	///
+	/// * `factor` - the factor to multiply the lightness by
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	///
+	/// let hsl = Hsl::new_unchecked(180, 50, 50);
+	/// assert_eq!(hsl.scale_lightness(1.2), Hsl::new_unchecked(180, 50, 60));
+	/// ```
+	///
+	pub fn scale_lightness(self, factor: f64) -> Hsl {
+		Self::new_unchecked(
+			self.hue,
+			self.saturation,
+			Self::_scale(self.lightness, factor),
+		)
+	}
+
+	///
+	/// Returns a color with the saturation multiplied by `factor`, rounded
+	/// and clamped to the range 0 to 100. Factors above 1.0 saturate the
+	/// color and factors below 1.0 desaturate it.
+	///
+	/// # Arguments
+	///
+	/// * `factor` - the factor to multiply the saturation by
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	///
+	/// let hsl = Hsl::new_unchecked(180, 50, 50);
+	/// assert_eq!(hsl.scale_saturation(0.5), Hsl::new_unchecked(180, 25, 50));
+	/// ```
+	///
+	pub fn scale_saturation(self, factor: f64) -> Hsl {
+		Self::new_unchecked(
+			self.hue,
+			Self::_scale(self.saturation, factor),
+			self.lightness,
+		)
+	}
+
+	fn _scale(percentage: u8, factor: f64) -> u8 {
+		(percentage as f64 * factor).clamp(0., 100.).round() as u8
+	}
+
+	///
+	/// Returns the complementary color, whose hue lies opposite on the color
+	/// wheel.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	///
+	/// let hsl = Hsl::new_unchecked(300, 100, 50);
+	/// assert_eq!(hsl.complement(), Hsl::new_unchecked(120, 100, 50));
+	/// ```
+	///
+	pub fn complement(self) -> Hsl {
+		self.rotate_hue(180)
+	}
+
+	///
+	/// Returns the triadic color scheme, made up of the color and the two
+	/// colors evenly spaced 120° apart from it on the color wheel.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	///
+	/// let [first, second, third] = Hsl::new_unchecked(0, 100, 50).triadic();
+	/// assert_eq!((first.hue, second.hue, third.hue), (0, 120, 240));
+	/// ```
+	///
+	pub fn triadic(self) -> [Hsl; 3] {
+		[
+			self.rotate_hue(0),
+			self.rotate_hue(120),
+			self.rotate_hue(240),
+		]
+	}
+
+	///
+	/// Returns the analogous color scheme, made up of the color surrounded
+	/// by the two colors `angle` degrees away from it on either side of the
+	/// color wheel.
+	///
+	/// # Arguments
+	///
+	/// * `angle` - the hue distance, in degrees, between adjacent colors
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	///
+	/// let [first, second, third] = Hsl::new_unchecked(10, 100, 50).analogous(30);
+	/// assert_eq!((first.hue, second.hue, third.hue), (340, 10, 40));
+	/// ```
+	///
+	pub fn analogous(self, angle: u16) -> [Hsl; 3] {
+		let angle = angle as i32;
+		[
+			self.rotate_hue(-angle),
+			self.rotate_hue(0),
+			self.rotate_hue(angle),
+		]
+	}
+
+	///
+	/// Returns the tetradic (square) color scheme, made up of the color and
+	/// the three colors evenly spaced 90° apart from it on the color wheel.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	///
+	/// let hues = Hsl::new_unchecked(45, 100, 50).tetradic().map(|hsl| hsl.hue);
+	/// assert_eq!(hues, [45, 135, 225, 315]);
+	/// ```
+	///
+	pub fn tetradic(self) -> [Hsl; 4] {
+		[
+			self.rotate_hue(0),
+			self.rotate_hue(90),
+			self.rotate_hue(180),
+			self.rotate_hue(270),
+		]
+	}
+
+	///
+	/// Returns a monochromatic palette of `count` colors sharing the hue and
+	/// saturation of the color, with lightness stepped evenly from dark to
+	/// light. Pure black and white are excluded since they carry no hue. A
+	/// `count` of 1 returns just the color itself.
+	///
+	/// # Arguments
+	///
+	/// * `count` - the number of colors in the palette
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	///
+	/// let palette = Hsl::new_unchecked(210, 60, 50).monochromatic(4);
+	/// let lightness: Vec<u8> = palette.iter().map(|hsl| hsl.lightness).collect();
+	///
+	/// assert_eq!(lightness, vec![20, 40, 60, 80]);
+	/// ```
+	///
+	pub fn monochromatic(self, count: usize) -> Vec<Hsl> {
+		if count == 1 {
+			return vec![self];
+		}
+
+		(1..=count)
+			.map(|step| {
+				let lightness = (step as f64 * 100. / (count + 1) as f64).round() as u8;
+				Hsl::new_unchecked(self.hue, self.saturation, lightness)
+			})
+			.collect()
+	}
+
+	///
+	/// Rotates the hue by a signed number of degrees, wrapping around into
+	/// the range `[0, 360)`. Negative values rotate backwards and values
+	/// beyond a full turn wrap around as many times as needed.
+	///
+	/// # Arguments
+	///
+	/// * `degrees` - the number of degrees to rotate the hue by
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	///
+	/// let hsl = Hsl::new_unchecked(0, 100, 50);
+	/// assert_eq!(hsl.rotate_hue(-90), Hsl::new_unchecked(270, 100, 50));
+	/// ```
+	///
+	pub fn rotate_hue(self, degrees: i32) -> Hsl {
+		let hue = (self.hue as i32 + degrees).rem_euclid(360) as u16;
+		Self::new_unchecked(hue, self.saturation, self.lightness)
+	}
+
+	///
+	/// Returns the shortest angular distance between the hues of two colors
+	/// in degrees, going whichever way around the color wheel is shorter.
+	/// The result ranges from `0` to `180`.
+	///
+	/// # Arguments
+	///
+	/// * `other` - the color to measure the distance to
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	///
+	/// let magenta_red = Hsl::new_unchecked(350, 100, 50);
+	/// let orange_red = Hsl::new_unchecked(10, 100, 50);
+	///
+	/// assert_eq!(magenta_red.hue_distance(orange_red), 20);
+	/// ```
+	///
+	pub fn hue_distance(self, other: Hsl) -> u16 {
+		let distance = (self.hue as i32 - other.hue as i32).rem_euclid(360) as u16;
+		distance.min(360 - distance)
+	}
+
+	///
+	/// Classifies the color into a basic, human-readable category for
+	/// tagging and search. The first matching row of the following table
+	/// wins:
+	///
+	/// | Condition            | Category   |
+	/// |----------------------|------------|
+	/// | lightness ≤ 10%      | `"black"`  |
+	/// | lightness ≥ 95%      | `"white"`  |
+	/// | saturation < 15%     | `"gray"`   |
+	/// | hue < 15° or ≥ 345°  | `"red"`    |
+	/// | hue < 45°            | `"orange"` |
+	/// | hue < 70°            | `"yellow"` |
+	/// | hue < 165°           | `"green"`  |
+	/// | hue < 195°           | `"cyan"`   |
+	/// | hue < 260°           | `"blue"`   |
+	/// | hue < 300°           | `"purple"` |
+	/// | hue < 345°           | `"pink"`   |
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	///
+	/// assert_eq!(Hsl::new_unchecked(180, 100, 50).basic_category(), "cyan");
+	/// assert_eq!(Hsl::new_unchecked(180, 5, 50).basic_category(), "gray");
+	/// ```
+	///
+	pub fn basic_category(self) -> &'static str {
+		if self.lightness <= 10 {
+			return "black";
+		}
+		if self.lightness >= 95 {
+			return "white";
+		}
+		if self.saturation < 15 {
+			return "gray";
+		}
+
+		match self.hue % 360 {
+			0..=14 | 345..=359 => "red",
+			15..=44 => "orange",
+			45..=69 => "yellow",
+			70..=164 => "green",
+			165..=194 => "cyan",
+			195..=259 => "blue",
+			260..=299 => "purple",
+			_ => "pink",
+		}
+	}
+
+	///
+	/// Interpolates between `self` and `other` by the factor `t`, which is
+	/// clamped to the range `[0.0, 1.0]`. The hue travels along the shortest
+	/// arc around the color wheel, wrapping through 0°, while the saturation
+	/// and lightness are interpolated linearly. When the hues are exactly
+	/// 180° apart, the hue decreases.
+	///
+	/// # Arguments
+	///
+	/// * `other` - the color to interpolate towards
+	/// * `t` - the interpolation factor
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	///
+	/// let magenta_red = Hsl::new_unchecked(350, 100, 50);
+	/// let orange_red = Hsl::new_unchecked(10, 100, 50);
+	///
+	/// assert_eq!(magenta_red.lerp(orange_red, 0.5), Hsl::new_unchecked(0, 100, 50));
+	/// ```
+	///
+	pub fn lerp(self, other: Hsl, t: f64) -> Hsl {
+		let t = t.clamp(0., 1.);
+		let from = (self.hue % 360) as f64;
+		let to = (other.hue % 360) as f64;
+		let arc = (to - from + 540.).rem_euclid(360.) - 180.;
+
+		let hue = (from + arc * t).round().rem_euclid(360.) as u16;
+		let apply = |from: u8, to: u8| (from as f64 + (to as f64 - from as f64) * t).round() as u8;
+
+		Hsl::new_unchecked(
+			hue,
+			apply(self.saturation, other.saturation),
+			apply(self.lightness, other.lightness),
+		)
+	}
+
+	///
+	/// Returns an iterator over `steps` colors evenly spaced around the hue
+	/// circle, starting at a hue of 0, with a fixed saturation and lightness.
+	/// Hues are truncated to whole degrees, and the saturation and lightness
+	/// are clamped to 100 as in [`Hsl::new_clamped`](self::Hsl::new_clamped).
+	///
+	/// # Arguments
+	///
+	/// * `saturation` - the saturation of every color
+	/// * `lightness` - the lightness of every color
+	/// * `steps` - the number of colors to yield
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	///
+	/// let hues: Vec<u16> = Hsl::hue_wheel(100, 50, 4).map(|hsl| hsl.hue).collect();
+	/// assert_eq!(hues, vec![0, 90, 180, 270]);
+	/// ```
+	///
+	pub fn hue_wheel(saturation: u8, lightness: u8, steps: usize) -> impl Iterator<Item = Hsl> {
+		(0..steps).map(move |step| {
+			let hue = (step * 360 / steps) as u16;
+			Hsl::new_clamped(hue, saturation, lightness)
+		})
+	}
+
+	///
+	/// Generates a random vivid color: the hue is drawn uniformly from
+	/// `rng`, while the saturation is kept between 80% and 100% and the
+	/// lightness between 45% and 60% so that the result is never washed out,
+	/// muddy, or too dark. Useful for placeholder and avatar colors.
+	///
+	/// # Arguments
+	///
+	/// * `rng` - the random number generator to draw from
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	/// use rand::SeedableRng;
+	/// use rand_chacha::ChaCha8Rng;
+	///
+	/// let mut rng = ChaCha8Rng::seed_from_u64(0);
+	/// let color = Hsl::random_vivid(&mut rng);
+	///
+	/// assert!(color.saturation >= 80);
+	/// ```
+	///
+	#[cfg(feature = "rand")]
+	pub fn random_vivid<R: rand::Rng>(rng: &mut R) -> Hsl {
+		Self::new_unchecked(
+			rng.gen_range(0..360),
+			rng.gen_range(80..=100),
+			rng.gen_range(45..=60),
+		)
+	}
+
+	///
+	/// Returns a [`HslBuilder`] for constructing a Hsl object one field at a
+	/// time. Unset fields default to 0.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	/// let cyan = Hsl::builder().hue(180).saturation(100).lightness(50).build()?;
+	/// # Ok::<(), color_conv::Error>(())
+	/// ```
+	///
+	pub fn builder() -> HslBuilder {
+		HslBuilder::default()
+	}
+}
+
+///
+/// A builder for [`Hsl`], created by [`Hsl::builder`](self::Hsl::builder).
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, Default)]
+pub struct HslBuilder {
+	hue: u16,
+	saturation: u8,
+	lightness: u8,
+}
+
+impl HslBuilder {
+	///
+	/// Sets the hue value of the color.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	/// let builder = Hsl::builder().hue(180);
+	/// ```
+	///
+	pub fn hue(mut self, hue: u16) -> Self {
+		self.hue = hue;
+		self
+	}
+
+	///
+	/// Sets the saturation value of the color.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	/// let builder = Hsl::builder().saturation(50);
+	/// ```
+	///
+	pub fn saturation(mut self, saturation: u8) -> Self {
+		self.saturation = saturation;
+		self
+	}
+
+	///
+	/// Sets the lightness value of the color.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	/// let builder = Hsl::builder().lightness(50);
+	/// ```
+	///
+	pub fn lightness(mut self, lightness: u8) -> Self {
+		self.lightness = lightness;
+		self
+	}
+
+	///
+	/// Returns a Result containing the built Hsl object. Performs the same
+	/// validation as [`Hsl::new`](self::Hsl::new).
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	/// let cyan = Hsl::builder().hue(180).saturation(100).lightness(50).build()?;
+	/// assert_eq!(cyan, Hsl::new(180, 100, 50)?);
+	/// # Ok::<(), color_conv::Error>(())
+	/// ```
+	///
+	pub fn build(self) -> Result<Hsl, Error> {
+		Hsl::new(self.hue, self.saturation, self.lightness)
+	}
+}
+
+///
+/// Defaults to `hsl(0°, 0%, 0%)`, which is black.
+///
+impl Default for Hsl {
+	fn default() -> Self {
+		Self::new_unchecked(0, 0, 0)
+	}
 }
 
 impl fmt::Display for Hsl {
@@ -79,26 +854,87 @@ impl fmt::Display for Hsl {
 	}
 }
 
+impl FromStr for Hsl {
+	type Err = Error;
+
+	///
+	/// Parses a string of the form `hsl(h, s%, l%)`, as produced by the
+	/// [`Display`](core::fmt::Display) implementation of [`Hsl`] or found in
+	/// CSS. The hue may carry a `deg` or `°` unit and the saturation and
+	/// lightness a `%` unit, all of which are optional; any other unit is
+	/// rejected. Whitespace around each value is ignored.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsl;
+	///
+	/// let cyan = "hsl(180deg, 100%, 50%)".parse::<Hsl>()?;
+	/// assert_eq!(cyan, Hsl::new(180, 100, 50)?);
+	///
+	/// assert!("hsl(180%, 100%, 50%)".parse::<Hsl>().is_err());
+	/// # Ok::<(), color_conv::Error>(())
+	/// ```
+	///
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let args = parse::function_args(s, "hsl")?;
+
+		match args[..] {
+			[hue, saturation, lightness] => {
+				let percentage = |arg| {
+					u8::try_from(parse::percentage::<u16>(arg)?)
+						.map_err(|_| Error::PercentageOverflow)
+				};
+
+				Hsl::new(
+					parse::angle(hue)?,
+					percentage(saturation)?,
+					percentage(lightness)?,
+				)
+			}
+			_ => Err(Error::ParseError(format!(
+				"expected 3 values, got {}",
+				args.len()
+			))),
+		}
+	}
+}
+
 macro_rules! exclusive_range_workaround {
 	(
 		$self:ident,
 		$($range:expr => $tup:expr),*
 	) => ({
-		match $self.hue {
+		// A hue of 360 is equivalent to a hue of 0
+		match $self.hue % 360 {
 			$(
 				h if ($range).contains(&h) => $tup,
 			)*
-			_ => panic!("Unexpected hue: {}, larger than 360!", $self.hue),
+			_ => unreachable!(),
 		}
 	});
 }
 
+impl From<Rgb> for Hsl {
+	fn from(rgb: Rgb) -> Self {
+		rgb.to_hsl()
+	}
+}
+
+impl TryFrom<(u16, u8, u8)> for Hsl {
+	type Error = Error;
+
+	fn try_from((hue, saturation, lightness): (u16, u8, u8)) -> Result<Self, Self::Error> {
+		Hsl::new(hue, saturation, lightness)
+	}
+}
+
 impl Color for Hsl {
 	fn to_rgb(self) -> Rgb {
-		let c = (1. - ((2. * (self.lightness as f64 / 100.)) - 1.).abs())
-			* (self.saturation as f64 / 100.);
-		let x = c * (1. - ((((self.hue as f64) / 60.) % 2.) - 1.).abs());
-		let m = (self.lightness as f64 / 100.) - (c / 2.);
+		let c = (1. - ((2. * (self.lightness as Float / 100.)) - 1.).abs())
+			* (self.saturation as Float / 100.);
+		let x = c * (1. - ((((self.hue as Float) / 60.) % 2.) - 1.).abs());
+		let m = (self.lightness as Float / 100.) - (c / 2.);
 
 		let (r_prime, g_prime, b_prime) = exclusive_range_workaround! { self,
 			0..60 => (c, x, 0.),
@@ -109,7 +945,7 @@ impl Color for Hsl {
 			300..360 => (c, 0., x)
 		};
 
-		let apply = |v: f64| ((v + m) * 255.).round() as u8;
+		let apply = |v: Float| ((v + m) * 255.).round() as u8;
 		let red = apply(r_prime);
 		let green = apply(g_prime);
 		let blue = apply(b_prime);
@@ -128,11 +964,61 @@ impl Color for Hsl {
 	fn to_hsl(self) -> Hsl {
 		self
 	}
+
+	fn to_hsv(self) -> Hsv {
+		// Converts directly rather than through `Rgb` to avoid rounding to
+		// 8-bit channels along the way. The hue is unchanged
+		let saturation = self.saturation as Float / 100.;
+		let lightness = self.lightness as Float / 100.;
+
+		let value = lightness + saturation * lightness.min(1. - lightness);
+		let saturation = if value == 0. {
+			0.
+		} else {
+			2. * (1. - lightness / value)
+		};
+
+		Hsv::new_unchecked(
+			self.hue,
+			(saturation * 100.).round() as u8,
+			(value * 100.).round() as u8,
+		)
+	}
+
+	fn to_xyz(self) -> Xyz {
+		self.to_rgb().to_xyz()
+	}
+
+	fn to_lab(self) -> Lab {
+		self.to_rgb().to_lab()
+	}
+
+	fn to_hwb(self) -> Hwb {
+		self.to_rgb().to_hwb()
+	}
+
+	fn to_css(self) -> String {
+		format!(
+			"hsl({}, {}%, {}%)",
+			self.hue, self.saturation, self.lightness
+		)
+	}
+}
+
+impl Invert for Hsl {
+	fn invert(self) -> Hsl {
+		Hsl::new_unchecked(
+			(self.hue % 360 + 180) % 360,
+			self.saturation,
+			100u8.saturating_sub(self.lightness),
+		)
+	}
 }
 
 #[cfg(test)]
 mod test {
 	use super::*;
+	use crate::testing;
 
 	#[test]
 	fn test_to_string() {
@@ -157,4 +1043,534 @@ mod test {
 	fn test_checked_hsl() {
 		Hsl::new(361, 101, 101).unwrap();
 	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn test_serde() {
+		let hsl = Hsl::new_unchecked(180, 100, 50);
+		let json = serde_json::to_string(&hsl).unwrap();
+		assert_eq!(json, r#"{"hue":180,"saturation":100,"lightness":50}"#);
+		assert_eq!(serde_json::from_str::<Hsl>(&json).unwrap(), hsl);
+	}
+
+	#[test]
+	fn test_invert() {
+		let black = Hsl::new_unchecked(0, 0, 0);
+		let white = Hsl::new_unchecked(0, 0, 100);
+		assert_eq!(black.invert().to_rgb(), white.to_rgb());
+		assert_eq!(white.invert().to_rgb(), black.to_rgb());
+		assert_eq!(
+			Hsl::new_unchecked(30, 50, 60).invert(),
+			Hsl::new_unchecked(210, 50, 40)
+		);
+	}
+
+	#[test]
+	fn test_invert_unchecked() {
+		assert_eq!(
+			Hsl::new_unchecked(30, 50, 150).invert(),
+			Hsl::new_unchecked(210, 50, 0)
+		);
+		assert_eq!(
+			Hsl::new_unchecked(u16::MAX, 50, 60).invert(),
+			Hsl::new_unchecked(195, 50, 40)
+		);
+	}
+
+	#[test]
+	fn test_from_rgb() {
+		let hsl: Hsl = Rgb::new(0, 255, 255).into();
+		assert_eq!(hsl, Hsl::new_unchecked(180, 100, 50));
+	}
+
+	#[test]
+	fn test_try_from_tuple() {
+		assert_eq!(
+			Hsl::try_from((180, 100, 50)).unwrap(),
+			Hsl::new_unchecked(180, 100, 50)
+		);
+		assert!(matches!(
+			Hsl::try_from((361, 100, 50)),
+			Err(Error::DegreeOverflow)
+		));
+	}
+
+	#[test]
+	fn test_lighten_darken() {
+		let hsl = Hsl::new_unchecked(30, 50, 60);
+		assert_eq!(hsl.lighten(10), Hsl::new_unchecked(30, 50, 70));
+		assert_eq!(hsl.darken(10), Hsl::new_unchecked(30, 50, 50));
+	}
+
+	#[test]
+	fn test_lighten_darken_saturates() {
+		let hsl = Hsl::new_unchecked(30, 50, 60);
+		assert_eq!(hsl.lighten(41), Hsl::new_unchecked(30, 50, 100));
+		assert_eq!(hsl.lighten(255), Hsl::new_unchecked(30, 50, 100));
+		assert_eq!(hsl.darken(61), Hsl::new_unchecked(30, 50, 0));
+		assert_eq!(hsl.darken(255), Hsl::new_unchecked(30, 50, 0));
+	}
+
+	#[test]
+	fn test_saturate_desaturate_saturates() {
+		let hsl = Hsl::new_unchecked(30, 50, 60);
+		assert_eq!(hsl.saturate(10), Hsl::new_unchecked(30, 60, 60));
+		assert_eq!(hsl.desaturate(10), Hsl::new_unchecked(30, 40, 60));
+		assert_eq!(hsl.saturate(255), Hsl::new_unchecked(30, 100, 60));
+		assert_eq!(hsl.desaturate(255), Hsl::new_unchecked(30, 0, 60));
+	}
+
+	#[test]
+	fn test_complement() {
+		assert_eq!(
+			Hsl::new_unchecked(30, 50, 60).complement(),
+			Hsl::new_unchecked(210, 50, 60)
+		);
+		assert_eq!(
+			Hsl::new_unchecked(200, 50, 60).complement(),
+			Hsl::new_unchecked(20, 50, 60)
+		);
+	}
+
+	#[test]
+	fn test_triadic() {
+		let hues = Hsl::new_unchecked(300, 50, 60).triadic().map(|hsl| hsl.hue);
+		assert_eq!(hues, [300, 60, 180]);
+	}
+
+	#[test]
+	fn test_analogous() {
+		let hues = Hsl::new_unchecked(30, 50, 60)
+			.analogous(30)
+			.map(|hsl| hsl.hue);
+		assert_eq!(hues, [0, 30, 60]);
+
+		let hues = Hsl::new_unchecked(350, 50, 60)
+			.analogous(45)
+			.map(|hsl| hsl.hue);
+		assert_eq!(hues, [305, 350, 35]);
+	}
+
+	#[test]
+	fn test_tetradic() {
+		let scheme = Hsl::new_unchecked(300, 50, 60).tetradic();
+		assert_eq!(scheme.map(|hsl| hsl.hue), [300, 30, 120, 210]);
+		assert!(scheme
+			.iter()
+			.all(|hsl| hsl.saturation == 50 && hsl.lightness == 60));
+	}
+
+	#[test]
+	fn test_monochromatic() {
+		let palette = Hsl::new_unchecked(120, 40, 50).monochromatic(5);
+
+		assert_eq!(
+			palette.iter().map(|hsl| hsl.lightness).collect::<Vec<_>>(),
+			vec![17, 33, 50, 67, 83]
+		);
+		assert!(palette
+			.iter()
+			.all(|hsl| hsl.hue == 120 && hsl.saturation == 40));
+		assert!(palette
+			.windows(2)
+			.all(|pair| pair[0].lightness < pair[1].lightness));
+	}
+
+	#[test]
+	fn test_monochromatic_edge_cases() {
+		let hsl = Hsl::new_unchecked(120, 40, 75);
+
+		assert_eq!(hsl.monochromatic(1), vec![hsl]);
+		assert!(hsl.monochromatic(0).is_empty());
+	}
+
+	#[test]
+	fn test_builder() {
+		let hsl = Hsl::builder()
+			.hue(30)
+			.saturation(50)
+			.lightness(60)
+			.build()
+			.unwrap();
+		assert_eq!(hsl, Hsl::new_unchecked(30, 50, 60));
+	}
+
+	#[test]
+	fn test_builder_partial() {
+		let hsl = Hsl::builder().lightness(60).build().unwrap();
+		assert_eq!(hsl, Hsl::new_unchecked(0, 0, 60));
+		assert_eq!(Hsl::builder().build().unwrap(), Hsl::new_unchecked(0, 0, 0));
+	}
+
+	#[test]
+	fn test_builder_checked() {
+		let result = Hsl::builder().hue(361).build();
+		assert!(matches!(result, Err(Error::DegreeOverflow)));
+	}
+
+	#[test]
+	fn test_default() {
+		assert_eq!(Hsl::default(), Hsl::new_unchecked(0, 0, 0));
+		assert_eq!(Hsl::default().to_rgb(), Rgb::new(0, 0, 0));
+	}
+
+	#[cfg(feature = "rand")]
+	#[test]
+	fn test_random_vivid() {
+		use rand::SeedableRng;
+		use rand_chacha::ChaCha8Rng;
+
+		let colors = |seed| {
+			let mut rng = ChaCha8Rng::seed_from_u64(seed);
+			(0..64)
+				.map(|_| Hsl::random_vivid(&mut rng))
+				.collect::<Vec<_>>()
+		};
+
+		assert_eq!(colors(42), colors(42));
+		for color in colors(42) {
+			assert!(color.hue < 360);
+			assert!((80..=100).contains(&color.saturation));
+			assert!((45..=60).contains(&color.lightness));
+		}
+	}
+
+	#[test]
+	fn test_to_rgb_hue_360() {
+		let hsl = Hsl::new(360, 100, 50).unwrap();
+		assert_eq!(hsl.to_rgb(), Rgb::new(255, 0, 0));
+		assert_eq!(hsl.to_rgb(), Hsl::new_unchecked(0, 100, 50).to_rgb());
+	}
+
+	#[test]
+	fn test_new_clamped() {
+		assert_eq!(
+			Hsl::new_clamped(400, 150, 50),
+			Hsl::new_unchecked(40, 100, 50)
+		);
+		assert_eq!(
+			Hsl::new_clamped(360, 100, 255),
+			Hsl::new_unchecked(0, 100, 100)
+		);
+		assert_eq!(
+			Hsl::new_clamped(180, 50, 50),
+			Hsl::new_unchecked(180, 50, 50)
+		);
+	}
+
+	#[test]
+	fn test_new_wrapping() {
+		assert_eq!(
+			Hsl::new_wrapping(365, 100, 50).unwrap(),
+			Hsl::new_unchecked(5, 100, 50)
+		);
+		assert_eq!(
+			Hsl::new_wrapping(360, 50, 50).unwrap(),
+			Hsl::new_unchecked(0, 50, 50)
+		);
+		assert_eq!(
+			Hsl::new_wrapping(u16::MAX, 50, 50).unwrap(),
+			Hsl::new_unchecked(15, 50, 50)
+		);
+		assert_eq!(
+			Hsl::new_wrapping(180, 50, 50).unwrap(),
+			Hsl::new_unchecked(180, 50, 50)
+		);
+	}
+
+	#[test]
+	fn test_new_wrapping_checked() {
+		assert!(matches!(
+			Hsl::new_wrapping(365, 101, 50),
+			Err(Error::PercentageOverflow)
+		));
+		assert!(matches!(
+			Hsl::new_wrapping(10, 50, 101),
+			Err(Error::PercentageOverflow)
+		));
+	}
+
+	#[test]
+	fn test_hue_distance() {
+		let hue = |hue: u16| Hsl::new_unchecked(hue, 100, 50);
+
+		assert_eq!(hue(30).hue_distance(hue(90)), 60);
+		assert_eq!(hue(90).hue_distance(hue(30)), 60);
+		assert_eq!(hue(0).hue_distance(hue(180)), 180);
+		assert_eq!(hue(120).hue_distance(hue(120)), 0);
+		assert_eq!(hue(0).hue_distance(hue(360)), 0);
+	}
+
+	#[test]
+	fn test_hue_distance_wrapping() {
+		let hue = |hue: u16| Hsl::new_unchecked(hue, 100, 50);
+
+		assert_eq!(hue(350).hue_distance(hue(10)), 20);
+		assert_eq!(hue(10).hue_distance(hue(350)), 20);
+		assert_eq!(hue(300).hue_distance(hue(60)), 120);
+		assert_eq!(hue(270).hue_distance(hue(80)), 170);
+	}
+
+	#[test]
+	fn test_rotate_hue() {
+		let hsl = Hsl::new_unchecked(30, 50, 60);
+
+		assert_eq!(hsl.rotate_hue(90), Hsl::new_unchecked(120, 50, 60));
+		assert_eq!(hsl.rotate_hue(330), Hsl::new_unchecked(0, 50, 60));
+		assert_eq!(hsl.rotate_hue(-90), Hsl::new_unchecked(300, 50, 60));
+		assert_eq!(hsl.rotate_hue(360), hsl);
+		assert_eq!(hsl.rotate_hue(-360), hsl);
+		assert_eq!(hsl.rotate_hue(3 * 360 + 10), Hsl::new_unchecked(40, 50, 60));
+		assert_eq!(
+			hsl.rotate_hue(-2 * 360 - 40),
+			Hsl::new_unchecked(350, 50, 60)
+		);
+		assert_eq!(
+			Hsl::new_unchecked(0, 100, 50).rotate_hue(-90),
+			Hsl::new_unchecked(270, 100, 50)
+		);
+	}
+
+	#[test]
+	fn test_scale_lightness() {
+		let hsl = Hsl::new_unchecked(180, 50, 50);
+		assert_eq!(hsl.scale_lightness(1.2), Hsl::new_unchecked(180, 50, 60));
+		assert_eq!(hsl.scale_lightness(0.5), Hsl::new_unchecked(180, 50, 25));
+		assert_eq!(hsl.scale_lightness(1.), hsl);
+		assert_eq!(hsl.scale_lightness(3.), Hsl::new_unchecked(180, 50, 100));
+		assert_eq!(hsl.scale_lightness(-1.), Hsl::new_unchecked(180, 50, 0));
+	}
+
+	#[test]
+	fn test_scale_saturation() {
+		let hsl = Hsl::new_unchecked(180, 50, 50);
+		assert_eq!(hsl.scale_saturation(1.5), Hsl::new_unchecked(180, 75, 50));
+		assert_eq!(hsl.scale_saturation(0.1), Hsl::new_unchecked(180, 5, 50));
+		assert_eq!(hsl.scale_saturation(2.5), Hsl::new_unchecked(180, 100, 50));
+	}
+
+	#[test]
+	fn test_to_css() {
+		let hsl = Hsl::new_unchecked(180, 100, 50);
+		assert_eq!(hsl.to_css(), String::from("hsl(180, 100%, 50%)"));
+	}
+
+	#[test]
+	fn test_with_channels() {
+		let hsl = Hsl::new_unchecked(180, 100, 50);
+
+		assert_eq!(hsl.with_hue(90).unwrap(), Hsl::new_unchecked(90, 100, 50));
+		assert_eq!(
+			hsl.with_saturation(20).unwrap(),
+			Hsl::new_unchecked(180, 20, 50)
+		);
+		assert_eq!(
+			hsl.with_lightness(75).unwrap(),
+			Hsl::new_unchecked(180, 100, 75)
+		);
+	}
+
+	#[test]
+	fn test_with_channels_checked() {
+		let hsl = Hsl::new_unchecked(180, 100, 50);
+
+		assert!(matches!(hsl.with_hue(361), Err(Error::DegreeOverflow)));
+		assert!(matches!(
+			hsl.with_saturation(101),
+			Err(Error::PercentageOverflow)
+		));
+		assert!(matches!(
+			hsl.with_lightness(255),
+			Err(Error::PercentageOverflow)
+		));
+	}
+
+	#[test]
+	fn test_set_channels() {
+		let mut hsl = Hsl::new_unchecked(180, 100, 50);
+
+		hsl.set_hue(90).unwrap();
+		hsl.set_saturation(20).unwrap();
+		hsl.set_lightness(75).unwrap();
+		assert_eq!(hsl, Hsl::new_unchecked(90, 20, 75));
+	}
+
+	#[test]
+	fn test_set_channels_checked() {
+		let mut hsl = Hsl::new_unchecked(180, 100, 50);
+
+		assert!(matches!(hsl.set_hue(361), Err(Error::DegreeOverflow)));
+		assert!(matches!(
+			hsl.set_saturation(101),
+			Err(Error::PercentageOverflow)
+		));
+		assert!(matches!(
+			hsl.set_lightness(101),
+			Err(Error::PercentageOverflow)
+		));
+		assert_eq!(hsl, Hsl::new_unchecked(180, 100, 50));
+	}
+
+	#[test]
+	fn test_hue_wheel() {
+		let wheel: Vec<Hsl> = Hsl::hue_wheel(80, 40, 12).collect();
+
+		assert_eq!(wheel.len(), 12);
+		for (step, hsl) in wheel.iter().enumerate() {
+			assert_eq!(*hsl, Hsl::new_unchecked(step as u16 * 30, 80, 40));
+		}
+
+		let hues: Vec<u16> = Hsl::hue_wheel(100, 50, 7).map(|hsl| hsl.hue).collect();
+		assert_eq!(hues, vec![0, 51, 102, 154, 205, 257, 308]);
+	}
+
+	#[test]
+	fn test_hue_wheel_edge_cases() {
+		assert_eq!(Hsl::hue_wheel(100, 50, 0).count(), 0);
+		assert_eq!(
+			Hsl::hue_wheel(200, 200, 1).collect::<Vec<_>>(),
+			vec![Hsl::new_unchecked(0, 100, 100)]
+		);
+	}
+
+	#[test]
+	fn test_lerp_wraps_hue() {
+		let from = Hsl::new_unchecked(350, 100, 50);
+		let to = Hsl::new_unchecked(10, 100, 50);
+
+		assert_eq!(from.lerp(to, 0.5), Hsl::new_unchecked(0, 100, 50));
+		assert_eq!(from.lerp(to, 0.25), Hsl::new_unchecked(355, 100, 50));
+		assert_eq!(to.lerp(from, 0.25), Hsl::new_unchecked(5, 100, 50));
+		assert_eq!(Hsl::new_unchecked(360, 100, 50).lerp(to, 1.), to);
+	}
+
+	#[test]
+	fn test_lerp() {
+		let from = Hsl::new_unchecked(60, 20, 30);
+		let to = Hsl::new_unchecked(120, 80, 70);
+
+		assert_eq!(from.lerp(to, 0.), from);
+		assert_eq!(from.lerp(to, 1.), to);
+		assert_eq!(from.lerp(to, 0.5), Hsl::new_unchecked(90, 50, 50));
+		assert_eq!(from.lerp(to, 2.), to);
+		assert_eq!(
+			Hsl::new_unchecked(0, 100, 50).lerp(Hsl::new_unchecked(180, 100, 50), 0.5),
+			Hsl::new_unchecked(270, 100, 50)
+		);
+	}
+
+	#[test]
+	fn test_basic_category() {
+		let category = |hue, saturation, lightness| {
+			Hsl::new_unchecked(hue, saturation, lightness).basic_category()
+		};
+
+		assert_eq!(category(0, 100, 5), "black");
+		assert_eq!(category(200, 0, 0), "black");
+		assert_eq!(category(60, 100, 97), "white");
+		assert_eq!(category(0, 0, 100), "white");
+		assert_eq!(category(0, 0, 50), "gray");
+		assert_eq!(category(120, 10, 70), "gray");
+
+		assert_eq!(category(0, 100, 50), "red");
+		assert_eq!(category(350, 80, 40), "red");
+		assert_eq!(category(360, 100, 50), "red");
+		assert_eq!(category(30, 100, 50), "orange");
+		assert_eq!(category(39, 100, 50), "orange");
+		assert_eq!(category(60, 100, 50), "yellow");
+		assert_eq!(category(120, 100, 25), "green");
+		assert_eq!(category(90, 60, 50), "green");
+		assert_eq!(category(180, 100, 50), "cyan");
+		assert_eq!(category(240, 100, 50), "blue");
+		assert_eq!(category(210, 50, 40), "blue");
+		assert_eq!(category(270, 100, 50), "purple");
+		assert_eq!(category(300, 100, 25), "pink");
+		assert_eq!(category(330, 100, 70), "pink");
+	}
+
+	#[test]
+	fn test_from_str() {
+		let cyan = Hsl::new_unchecked(180, 100, 50);
+
+		assert_eq!("hsl(180deg, 100%, 50%)".parse::<Hsl>().unwrap(), cyan);
+		assert_eq!("hsl(180°, 100%, 50%)".parse::<Hsl>().unwrap(), cyan);
+		assert_eq!("hsl(180, 100%, 50%)".parse::<Hsl>().unwrap(), cyan);
+		assert_eq!(" hsl(180,100,50) ".parse::<Hsl>().unwrap(), cyan);
+		assert_eq!(cyan.to_string().parse::<Hsl>().unwrap(), cyan);
+	}
+
+	#[test]
+	fn test_from_str_invalid_units() {
+		assert!(matches!(
+			"hsl(180%, 100%, 50%)".parse::<Hsl>(),
+			Err(Error::ParseError(_))
+		));
+		assert!(matches!(
+			"hsl(180deg, 100deg, 50%)".parse::<Hsl>(),
+			Err(Error::ParseError(_))
+		));
+		assert!(matches!(
+			"hsl(0.5turn, 100%, 50%)".parse::<Hsl>(),
+			Err(Error::ParseError(_))
+		));
+	}
+
+	#[test]
+	fn test_from_str_invalid() {
+		assert!(matches!(
+			"hsl(361, 100%, 50%)".parse::<Hsl>(),
+			Err(Error::DegreeOverflow)
+		));
+		assert!(matches!(
+			"hsl(180, 300%, 50%)".parse::<Hsl>(),
+			Err(Error::PercentageOverflow)
+		));
+		assert!(matches!(
+			"hsl(180, 100%)".parse::<Hsl>(),
+			Err(Error::ParseError(_))
+		));
+		assert!(matches!(
+			"hsla(180, 100%, 50%, 1)".parse::<Hsl>(),
+			Err(Error::ParseError(_))
+		));
+	}
+
+	#[test]
+	fn test_to_hsv_direct() {
+		assert_eq!(
+			Hsl::new_unchecked(180, 100, 50).to_hsv(),
+			Hsv::new_unchecked(180, 100, 100)
+		);
+		assert_eq!(
+			Hsl::new_unchecked(0, 0, 0).to_hsv(),
+			Hsv::new_unchecked(0, 0, 0)
+		);
+		assert_eq!(
+			Hsl::new_unchecked(0, 0, 100).to_hsv(),
+			Hsv::new_unchecked(0, 0, 100)
+		);
+		assert_eq!(
+			Hsl::new_unchecked(30, 50, 60).to_hsv(),
+			Hsl::new_unchecked(30, 50, 60).to_rgb().to_hsv()
+		);
+
+		for hue in (0..=360).step_by(15) {
+			for saturation in (0..=100).step_by(5) {
+				for lightness in (0..=100).step_by(5) {
+					let hsl = Hsl::new_unchecked(hue, saturation, lightness);
+					let hsv = hsl.to_hsv();
+					assert_eq!(hsv.hue, hue);
+
+					let error = testing::max_channel_error(hsv.to_rgb(), hsl.to_rgb());
+					assert!(error <= testing::HSL_TOLERANCE, "{:?}", hsl);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_to_tuple() {
+		let hsl = Hsl::new_unchecked(180, 100, 50);
+		assert_eq!(Hsl::try_from(hsl.to_tuple()).unwrap(), hsl);
+	}
 }
@@ -0,0 +1,421 @@
+use crate::{css, parse, prelude::*};
+use core::{fmt, str::FromStr};
+
+///
+/// A representation of the HSLA (hue, saturation, lightness, alpha) color
+/// format.
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hsla {
+	/// Hue value (in degrees)
+	pub hue: u16,
+	/// Saturation percentage
+	pub saturation: u8,
+	/// Lightness percentage
+	pub lightness: u8,
+	/// Alpha value, where 0.0 is fully transparent and 1.0 is fully opaque
+	pub alpha: f64,
+}
+
+impl Hsla {
+	///
+	/// Returns a Result containing a new Hsla object given hue, saturation,
+	/// lightness, and alpha values. Will return an [`Error`] if either the
+	/// saturation or lightness are larger than 100, the hue is greater than
+	/// 360, or the alpha is not between 0.0 and 1.0.
+	///
+	/// # Arguments
+	///
+	/// * `hue` - the hue value of the color
+	/// * `saturation` - the saturation value of the color
+	/// * `lightness` - the lightness value of the color
+	/// * `alpha` - the alpha value of the color
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsla;
+	/// let translucent_cyan = Hsla::new(180, 100, 50, 0.5)?;
+	/// # Ok::<(), color_conv::Error>(())
+	/// ```
+	///
+	pub fn new(hue: u16, saturation: u8, lightness: u8, alpha: f64) -> Result<Self, Error> {
+		let hsl = Hsl::new(hue, saturation, lightness)?;
+
+		if !(0. ..=1.).contains(&alpha) {
+			return Err(Error::AlphaOverflow);
+		}
+
+		Ok(Self::from_hsl(hsl, alpha))
+	}
+
+	///
+	/// See [`Hsla::new`](self::Hsla::new). Does not perform check to ensure
+	/// that all parameters are valid. This is useful for when you know more
+	/// than the compiler about which values are being passed to the method.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsla;
+	/// let translucent_cyan = Hsla::new_unchecked(180, 100, 50, 0.5);
+	/// ```
+	///
+	pub const fn new_unchecked(hue: u16, saturation: u8, lightness: u8, alpha: f64) -> Self {
+		Self {
+			hue,
+			saturation,
+			lightness,
+			alpha,
+		}
+	}
+
+	///
+	/// Returns a new Hsla object given an [`Hsl`] color and an alpha value.
+	/// The alpha channel can be dropped again with
+	/// [`Color::to_hsl`](crate::Color::to_hsl).
+	///
+	/// # Arguments
+	///
+	/// * `hsl` - the color
+	/// * `alpha` - the alpha value of the color
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Color;
+	/// use color_conv::Hsl;
+	/// use color_conv::Hsla;
+	///
+	/// let cyan = Hsl::new_unchecked(180, 100, 50);
+	/// let translucent_cyan = Hsla::from_hsl(cyan, 0.5);
+	///
+	/// assert_eq!(translucent_cyan, Hsla::new_unchecked(180, 100, 50, 0.5));
+	/// assert_eq!(translucent_cyan.to_hsl(), cyan);
+	/// ```
+	///
+	pub const fn from_hsl(hsl: Hsl, alpha: f64) -> Self {
+		Self::new_unchecked(hsl.hue, hsl.saturation, hsl.lightness, alpha)
+	}
+
+	///
+	/// Converts to [`Rgba`], scaling the alpha value to the range 0 to 255
+	/// and rounding it.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::{Hsla, Rgba};
+	///
+	/// let rgba = Hsla::new_unchecked(180, 100, 50, 0.5).to_rgba();
+	/// assert_eq!(rgba, Rgba::new(0, 255, 255, 128));
+	/// ```
+	///
+	pub fn to_rgba(self) -> Rgba {
+		Rgba::from_rgb(self.to_rgb(), (self.alpha * 255.).round() as u8)
+	}
+
+	///
+	/// Converts to a CSS `hsla(...)` string like
+	/// [`Color::to_css`](crate::Color::to_css), but with the alpha value
+	/// always written to exactly `decimals` decimal places rather than in
+	/// its shortest form.
+	///
+	/// # Arguments
+	///
+	/// * `decimals` - the number of decimal places to write the alpha with
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsla;
+	///
+	/// let hsla = Hsla::new_unchecked(180, 100, 50, 0.5);
+	/// assert_eq!(hsla.to_css_precise(3), String::from("hsla(180, 100%, 50%, 0.500)"));
+	/// ```
+	///
+	pub fn to_css_precise(self, decimals: usize) -> String {
+		format!(
+			"hsla({}, {}%, {}%, {:.*})",
+			self.hue, self.saturation, self.lightness, decimals, self.alpha
+		)
+	}
+}
+
+impl fmt::Display for Hsla {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"hsla({}°, {}%, {}%, {})",
+			self.hue,
+			self.saturation,
+			self.lightness,
+			css::alpha(self.alpha)
+		)
+	}
+}
+
+impl FromStr for Hsla {
+	type Err = Error;
+
+	///
+	/// Parses a string of the form `hsla(h, s%, l%, a)`, as produced by the
+	/// [`Display`](core::fmt::Display) implementation of [`Hsla`], or
+	/// `hsl(h, s%, l%)`, in which case the color is fully opaque. As with
+	/// [`Hsl`], the hue may be suffixed with `deg` or `°` and the saturation
+	/// and lightness with `%`, but no other units are accepted. The alpha may
+	/// also be written as a percentage. Whitespace around each value is
+	/// ignored.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsla;
+	///
+	/// let translucent_cyan = "hsla(180°, 100%, 50%, 0.5)".parse::<Hsla>()?;
+	/// assert_eq!(translucent_cyan, Hsla::new(180, 100, 50, 0.5)?);
+	///
+	/// let cyan = "hsl(180, 100%, 50%)".parse::<Hsla>()?;
+	/// assert_eq!(cyan, Hsla::new(180, 100, 50, 1.)?);
+	/// # Ok::<(), color_conv::Error>(())
+	/// ```
+	///
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let args = if s.trim_start().starts_with("hsla") {
+			parse::function_args(s, "hsla")?
+		} else {
+			parse::function_args(s, "hsl")?
+		};
+
+		match args[..] {
+			[hue, saturation, lightness] => Hsla::new(
+				parse::angle(hue)?,
+				parse::percentage(saturation)?,
+				parse::percentage(lightness)?,
+				1.,
+			),
+			[hue, saturation, lightness, alpha] => Hsla::new(
+				parse::angle(hue)?,
+				parse::percentage(saturation)?,
+				parse::percentage(lightness)?,
+				parse::alpha(alpha)?,
+			),
+			_ => Err(Error::ParseError(format!(
+				"expected 3 or 4 values, got {}",
+				args.len()
+			))),
+		}
+	}
+}
+
+impl From<Rgba> for Hsla {
+	fn from(rgba: Rgba) -> Self {
+		Hsla::from_hsl(rgba.to_hsl(), rgba.alpha as f64 / 255.)
+	}
+}
+
+impl From<Hsla> for Rgba {
+	fn from(hsla: Hsla) -> Self {
+		hsla.to_rgba()
+	}
+}
+
+impl Color for Hsla {
+	fn to_rgb(self) -> Rgb {
+		self.to_hsl().to_rgb()
+	}
+
+	fn to_cmyk(self) -> Cmyk {
+		self.to_rgb().to_cmyk()
+	}
+
+	fn to_hsl(self) -> Hsl {
+		Hsl::new_unchecked(self.hue, self.saturation, self.lightness)
+	}
+
+	fn to_hsv(self) -> Hsv {
+		self.to_rgb().to_hsv()
+	}
+
+	fn to_hwb(self) -> Hwb {
+		self.to_rgb().to_hwb()
+	}
+
+	fn to_xyz(self) -> Xyz {
+		self.to_rgb().to_xyz()
+	}
+
+	fn to_lab(self) -> Lab {
+		self.to_rgb().to_lab()
+	}
+
+	fn to_hex_string(self) -> String {
+		self.to_rgba().to_hex_string()
+	}
+
+	fn to_css(self) -> String {
+		format!(
+			"hsla({}, {}%, {}%, {})",
+			self.hue,
+			self.saturation,
+			self.lightness,
+			css::alpha(self.alpha)
+		)
+	}
+}
+
+impl Invert for Hsla {
+	fn invert(self) -> Hsla {
+		Hsla::from_hsl(self.to_hsl().invert(), self.alpha)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_to_string() {
+		let hsla = Hsla::new_unchecked(180, 100, 50, 0.5);
+		assert_eq!(hsla.to_string(), String::from("hsla(180°, 100%, 50%, 0.5)"));
+	}
+
+	#[test]
+	fn test_from_str() {
+		let hsla = "hsla(180°, 100%, 50%, 0.5)".parse::<Hsla>().unwrap();
+		assert_eq!(hsla, Hsla::new_unchecked(180, 100, 50, 0.5));
+
+		let hsla = " hsla(180,100,50,0.25) ".parse::<Hsla>().unwrap();
+		assert_eq!(hsla, Hsla::new_unchecked(180, 100, 50, 0.25));
+	}
+
+	#[test]
+	fn test_from_str_without_alpha() {
+		let hsla = "hsl(180°, 100%, 50%)".parse::<Hsla>().unwrap();
+		assert_eq!(hsla, Hsla::new_unchecked(180, 100, 50, 1.));
+	}
+
+	#[test]
+	fn test_from_str_invalid() {
+		assert!(matches!(
+			"hsla(180, 100%, 50%, 1.5)".parse::<Hsla>(),
+			Err(Error::AlphaOverflow)
+		));
+		assert!(matches!(
+			"hsl(180, 100%)".parse::<Hsla>(),
+			Err(Error::ParseError(_))
+		));
+		assert!(matches!(
+			"rgba(0, 255, 255, 0.5)".parse::<Hsla>(),
+			Err(Error::ParseError(_))
+		));
+	}
+
+	#[test]
+	fn test_from_str_units() {
+		let hsla = "hsla(180deg, 100%, 50%, 50%)".parse::<Hsla>().unwrap();
+		assert_eq!(hsla, Hsla::new_unchecked(180, 100, 50, 0.5));
+
+		assert!(matches!(
+			"hsla(180%, 100%, 50%, 0.5)".parse::<Hsla>(),
+			Err(Error::ParseError(_))
+		));
+		assert!(matches!(
+			"hsla(180, 100°, 50%, 0.5)".parse::<Hsla>(),
+			Err(Error::ParseError(_))
+		));
+		assert!(matches!(
+			"hsl(180%, 100%, 50%)".parse::<Hsla>(),
+			Err(Error::ParseError(_))
+		));
+	}
+
+	#[test]
+	fn test_display_round_trip() {
+		let hsla = Hsla::new_unchecked(30, 50, 60, 0.75);
+		assert_eq!(hsla.to_string().parse::<Hsla>().unwrap(), hsla);
+	}
+
+	#[test]
+	fn test_rgba_round_trip() {
+		let rgba = Rgba::new(0, 255, 255, 128);
+		let hsla = Hsla::from(rgba);
+		assert_eq!(hsla.to_hsl(), Hsl::new_unchecked(180, 100, 50));
+		assert!((hsla.alpha - 0.5).abs() < 0.01);
+		assert_eq!(Rgba::from(hsla), rgba);
+
+		let hsla = Hsla::new_unchecked(180, 100, 50, 0.5);
+		let back = Hsla::from(hsla.to_rgba());
+		assert_eq!(back.to_hsl(), hsla.to_hsl());
+		assert!((back.alpha - hsla.alpha).abs() < 1. / 255.);
+	}
+
+	#[test]
+	fn test_checked_hsla() {
+		assert!(matches!(
+			Hsla::new(180, 100, 50, -0.1),
+			Err(Error::AlphaOverflow)
+		));
+		assert!(matches!(
+			Hsla::new(180, 101, 50, 0.5),
+			Err(Error::PercentageOverflow)
+		));
+	}
+
+	#[test]
+	fn test_invert() {
+		let hsla = Hsla::new_unchecked(0, 100, 50, 0.5);
+		assert_eq!(hsla.invert(), Hsla::new_unchecked(180, 100, 50, 0.5));
+	}
+
+	#[test]
+	fn test_to_css() {
+		let hsla = Hsla::new_unchecked(180, 100, 50, 0.5);
+		assert_eq!(hsla.to_css(), String::from("hsla(180, 100%, 50%, 0.5)"));
+	}
+
+	#[test]
+	fn test_alpha_formatting() {
+		let alpha = |alpha: f64| Hsla::new_unchecked(180, 100, 50, alpha);
+
+		assert_eq!(
+			alpha(1.).to_string(),
+			String::from("hsla(180°, 100%, 50%, 1)")
+		);
+		assert_eq!(
+			alpha(0.5).to_css(),
+			String::from("hsla(180, 100%, 50%, 0.5)")
+		);
+		assert_eq!(
+			alpha(0.333).to_css(),
+			String::from("hsla(180, 100%, 50%, 0.333)")
+		);
+		assert_eq!(alpha(1.).to_css(), String::from("hsla(180, 100%, 50%, 1)"));
+		assert_eq!(
+			alpha(0.5).to_string(),
+			String::from("hsla(180°, 100%, 50%, 0.5)")
+		);
+		assert_eq!(
+			alpha(0.333).to_string(),
+			String::from("hsla(180°, 100%, 50%, 0.333)")
+		);
+		assert_eq!(
+			alpha(0.1 + 0.2).to_string(),
+			String::from("hsla(180°, 100%, 50%, 0.3)")
+		);
+
+		assert_eq!(
+			alpha(1.).to_css_precise(2),
+			String::from("hsla(180, 100%, 50%, 1.00)")
+		);
+		assert_eq!(
+			alpha(0.5).to_css_precise(3),
+			String::from("hsla(180, 100%, 50%, 0.500)")
+		);
+		assert_eq!(
+			alpha(0.333).to_css_precise(1),
+			String::from("hsla(180, 100%, 50%, 0.3)")
+		);
+	}
+}
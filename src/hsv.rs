@@ -0,0 +1,316 @@
+use crate::prelude::*;
+use core::{convert::TryFrom, fmt};
+
+///
+/// A representation of the HSV (hue, saturation, value) color format, also
+/// known as HSB (hue, saturation, brightness).
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hsv {
+	/// Hue value (in degrees)
+	pub hue: u16,
+	/// Saturation percentage
+	pub saturation: u8,
+	/// Value (brightness) percentage
+	pub value: u8,
+}
+
+impl Hsv {
+	///
+	/// Returns a Result containing a new Hsv object given hue, saturation,
+	/// and value values. Will return an [`Error`] if either the saturation
+	/// or value are larger than 100 due to the fact that they represent
+	/// percentages or the hue is greater than 360 because it represents a
+	/// degree value.
+	///
+	/// # Arguments
+	///
+	/// * `hue` - the hue value of the color
+	/// * `saturation` - the saturation value of the color
+	/// * `value` - the value (brightness) of the color
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsv;
+	/// let cyan = Hsv::new(180, 100, 100)?;
+	/// # Ok::<(), color_conv::Error>(())
+	/// ```
+	///
+	pub fn new(hue: u16, saturation: u8, value: u8) -> Result<Self, Error> {
+		if !(saturation <= 100 && value <= 100) {
+			return Err(Error::PercentageOverflow);
+		}
+
+		if hue > 360 {
+			return Err(Error::DegreeOverflow);
+		}
+
+		Ok(Self::new_unchecked(hue, saturation, value))
+	}
+
+	///
+	/// See [`Hsv::new`](self::Hsv::new). Does not perform check to ensure
+	/// that all parameters are valid. This is useful for when you know more
+	/// than the compiler about which values are being passed to the method.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsv;
+	/// let cyan = Hsv::new_unchecked(180, 100, 100);
+	/// ```
+	///
+	pub const fn new_unchecked(hue: u16, saturation: u8, value: u8) -> Self {
+		Self {
+			hue,
+			saturation,
+			value,
+		}
+	}
+
+	///
+	/// Returns the hue, saturation, and value as a tuple, in that order.
+	/// `Hsv::try_from` converts the tuple back.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hsv;
+	///
+	/// let hsv = Hsv::new_unchecked(180, 100, 100);
+	/// assert_eq!(hsv.to_tuple(), (180, 100, 100));
+	/// ```
+	///
+	pub const fn to_tuple(self) -> (u16, u8, u8) {
+		(self.hue, self.saturation, self.value)
+	}
+}
+
+impl fmt::Display for Hsv {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"hsv({}°, {}%, {}%)",
+			self.hue, self.saturation, self.value
+		)
+	}
+}
+
+impl From<Rgb> for Hsv {
+	fn from(rgb: Rgb) -> Self {
+		rgb.to_hsv()
+	}
+}
+
+impl TryFrom<(u16, u8, u8)> for Hsv {
+	type Error = Error;
+
+	fn try_from((hue, saturation, value): (u16, u8, u8)) -> Result<Self, Self::Error> {
+		Hsv::new(hue, saturation, value)
+	}
+}
+
+impl Color for Hsv {
+	fn to_rgb(self) -> Rgb {
+		let v = self.value as Float / 100.;
+		let c = v * (self.saturation as Float / 100.);
+		let x = c * (1. - ((((self.hue as Float) / 60.) % 2.) - 1.).abs());
+		let m = v - c;
+
+		// A hue of 360 is equivalent to a hue of 0
+		let (r_prime, g_prime, b_prime) = match (self.hue % 360) / 60 {
+			0 => (c, x, 0.),
+			1 => (x, c, 0.),
+			2 => (0., c, x),
+			3 => (0., x, c),
+			4 => (x, 0., c),
+			_ => (c, 0., x),
+		};
+
+		let apply = |v: Float| ((v + m) * 255.).round() as u8;
+		let red = apply(r_prime);
+		let green = apply(g_prime);
+		let blue = apply(b_prime);
+
+		Rgb { red, green, blue }
+	}
+
+	fn to_cmyk(self) -> Cmyk {
+		self.to_rgb().to_cmyk()
+	}
+
+	fn to_hex_string(self) -> String {
+		self.to_rgb().to_hex_string()
+	}
+
+	fn to_hsl(self) -> Hsl {
+		// Converts directly rather than through `Rgb` to avoid rounding to
+		// 8-bit channels along the way. The hue is unchanged
+		let saturation = self.saturation as Float / 100.;
+		let value = self.value as Float / 100.;
+
+		let lightness = value * (1. - saturation / 2.);
+		let saturation = if lightness == 0. || lightness == 1. {
+			0.
+		} else {
+			(value - lightness) / lightness.min(1. - lightness)
+		};
+
+		Hsl::new_unchecked(
+			self.hue,
+			(saturation * 100.).round() as u8,
+			(lightness * 100.).round() as u8,
+		)
+	}
+
+	fn to_hsv(self) -> Hsv {
+		self
+	}
+
+	fn to_xyz(self) -> Xyz {
+		self.to_rgb().to_xyz()
+	}
+
+	fn to_lab(self) -> Lab {
+		self.to_rgb().to_lab()
+	}
+
+	fn to_hwb(self) -> Hwb {
+		self.to_rgb().to_hwb()
+	}
+}
+
+impl Invert for Hsv {
+	fn invert(self) -> Hsv {
+		self.to_rgb().invert().to_hsv()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::testing;
+
+	#[test]
+	fn test_to_string() {
+		let hsv = Hsv::new_unchecked(180, 100, 100);
+		assert_eq!(hsv.to_string(), String::from("hsv(180°, 100%, 100%)"));
+	}
+
+	#[test]
+	fn test_to_hex_string() {
+		let hex = Hsv::new_unchecked(30, 50, 80).to_hex_string();
+		assert_eq!(hex, String::from("#cc9966"));
+	}
+
+	#[test]
+	fn test_to_rgb() {
+		let red = Hsv::new_unchecked(0, 100, 100).to_rgb();
+		assert_eq!(red, Rgb::new(255, 0, 0));
+
+		let cyan = Hsv::new_unchecked(180, 100, 100).to_rgb();
+		assert_eq!(cyan, Rgb::new(0, 255, 255));
+	}
+
+	#[test]
+	fn test_to_hsl() {
+		let hsl = Hsv::new_unchecked(180, 100, 100).to_hsl();
+		assert_eq!(hsl, Hsl::new_unchecked(180, 100, 50));
+	}
+
+	#[test]
+	fn test_rgb_round_trip() {
+		for rgb in [
+			Rgb::new(255, 0, 0),
+			Rgb::new(0, 255, 255),
+			Rgb::new(204, 153, 102),
+			Rgb::new(255, 255, 255),
+			Rgb::new(0, 0, 0),
+		]
+		.iter()
+		{
+			assert_eq!(rgb.to_hsv().to_rgb(), *rgb);
+		}
+	}
+
+	#[should_panic]
+	#[test]
+	fn test_checked_hsv() {
+		Hsv::new(361, 101, 101).unwrap();
+	}
+
+	#[test]
+	fn test_invert() {
+		let black = Hsv::new_unchecked(0, 0, 0);
+		let white = Hsv::new_unchecked(0, 0, 100);
+		assert_eq!(black.invert(), white);
+		assert_eq!(white.invert(), black);
+		assert_eq!(
+			Hsv::new_unchecked(0, 100, 100).invert(),
+			Hsv::new_unchecked(180, 100, 100)
+		);
+	}
+
+	#[test]
+	fn test_from_rgb() {
+		let hsv: Hsv = Rgb::new(0, 255, 255).into();
+		assert_eq!(hsv, Hsv::new_unchecked(180, 100, 100));
+	}
+
+	#[test]
+	fn test_try_from_tuple() {
+		assert_eq!(
+			Hsv::try_from((180, 100, 100)).unwrap(),
+			Hsv::new_unchecked(180, 100, 100)
+		);
+		assert!(matches!(
+			Hsv::try_from((180, 101, 100)),
+			Err(Error::PercentageOverflow)
+		));
+	}
+
+	#[test]
+	fn test_to_css() {
+		assert_eq!(
+			Hsv::new_unchecked(180, 100, 100).to_css(),
+			String::from("rgb(0, 255, 255)")
+		);
+	}
+
+	#[test]
+	fn test_to_hsl_direct() {
+		assert_eq!(
+			Hsv::new_unchecked(0, 0, 0).to_hsl(),
+			Hsl::new_unchecked(0, 0, 0)
+		);
+		assert_eq!(
+			Hsv::new_unchecked(0, 0, 100).to_hsl(),
+			Hsl::new_unchecked(0, 0, 100)
+		);
+		assert_eq!(
+			Hsv::new_unchecked(30, 50, 80).to_hsl(),
+			Hsv::new_unchecked(30, 50, 80).to_rgb().to_hsl()
+		);
+
+		for hue in (0..=360).step_by(15) {
+			for saturation in (0..=100).step_by(5) {
+				for value in (0..=100).step_by(5) {
+					let hsv = Hsv::new_unchecked(hue, saturation, value);
+					let hsl = hsv.to_hsl();
+					assert_eq!(hsl.hue, hue);
+
+					let error = testing::max_channel_error(hsl.to_rgb(), hsv.to_rgb());
+					assert!(error <= testing::HSL_TOLERANCE, "{:?}", hsv);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_to_tuple() {
+		let hsv = Hsv::new_unchecked(180, 100, 100);
+		assert_eq!(Hsv::try_from(hsv.to_tuple()).unwrap(), hsv);
+	}
+}
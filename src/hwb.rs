@@ -0,0 +1,288 @@
+use crate::prelude::*;
+use core::{convert::TryFrom, fmt};
+
+///
+/// A representation of the HWB (hue, whiteness, blackness) color format.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hwb {
+	/// Hue value (in degrees)
+	pub hue: u16,
+	/// Whiteness percentage
+	pub whiteness: u8,
+	/// Blackness percentage
+	pub blackness: u8,
+}
+
+impl Hwb {
+	///
+	/// Returns a Result containing a new Hwb object given hue, whiteness,
+	/// and blackness values. Will return an [`Error`] if either the
+	/// whiteness or blackness are larger than 100 due to the fact that they
+	/// represent percentages or the hue is greater than 360 because it
+	/// represents a degree value.
+	///
+	/// # Arguments
+	///
+	/// * `hue` - the hue value of the color
+	/// * `whiteness` - the whiteness value of the color
+	/// * `blackness` - the blackness value of the color
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hwb;
+	/// let cyan = Hwb::new(180, 0, 0)?;
+	/// # Ok::<(), color_conv::Error>(())
+	/// ```
+	///
+	/// # Note
+	///
+	/// * `whiteness` and `blackness` may add up to more than 100, in which
+	///   case they are normalized proportionally when converting to other
+	///   formats, producing a gray.
+	///
+	pub fn new(hue: u16, whiteness: u8, blackness: u8) -> Result<Self, Error> {
+		if !(whiteness <= 100 && blackness <= 100) {
+			return Err(Error::PercentageOverflow);
+		}
+
+		if hue > 360 {
+			return Err(Error::DegreeOverflow);
+		}
+
+		Ok(Self::new_unchecked(hue, whiteness, blackness))
+	}
+
+	///
+	/// See [`Hwb::new`](self::Hwb::new). Does not perform check to ensure
+	/// that all parameters are valid. This is useful for when you know more
+	/// than the compiler about which values are being passed to the method.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hwb;
+	/// let cyan = Hwb::new_unchecked(180, 0, 0);
+	/// ```
+	///
+	pub const fn new_unchecked(hue: u16, whiteness: u8, blackness: u8) -> Self {
+		Self {
+			hue,
+			whiteness,
+			blackness,
+		}
+	}
+
+	///
+	/// Returns the hue, whiteness, and blackness as a tuple, in that order.
+	/// `Hwb::try_from` converts the tuple back.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Hwb;
+	///
+	/// let hwb = Hwb::new_unchecked(180, 20, 30);
+	/// assert_eq!(hwb.to_tuple(), (180, 20, 30));
+	/// ```
+	///
+	pub const fn to_tuple(self) -> (u16, u8, u8) {
+		(self.hue, self.whiteness, self.blackness)
+	}
+}
+
+impl fmt::Display for Hwb {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"hwb({}°, {}%, {}%)",
+			self.hue, self.whiteness, self.blackness
+		)
+	}
+}
+
+impl From<Rgb> for Hwb {
+	fn from(rgb: Rgb) -> Self {
+		rgb.to_hwb()
+	}
+}
+
+impl TryFrom<(u16, u8, u8)> for Hwb {
+	type Error = Error;
+
+	fn try_from((hue, whiteness, blackness): (u16, u8, u8)) -> Result<Self, Self::Error> {
+		Hwb::new(hue, whiteness, blackness)
+	}
+}
+
+impl Color for Hwb {
+	fn to_rgb(self) -> Rgb {
+		let whiteness = self.whiteness as Float / 100.;
+		let blackness = self.blackness as Float / 100.;
+
+		// Whiteness and blackness adding up to 100% or more produces a gray
+		if whiteness + blackness >= 1. {
+			let gray = ((whiteness / (whiteness + blackness)) * 255.).round() as u8;
+			return Rgb::new(gray, gray, gray);
+		}
+
+		let x = 1. - ((((self.hue as Float) / 60.) % 2.) - 1.).abs();
+
+		// A hue of 360 is equivalent to a hue of 0
+		let (r_prime, g_prime, b_prime) = match (self.hue % 360) / 60 {
+			0 => (1., x, 0.),
+			1 => (x, 1., 0.),
+			2 => (0., 1., x),
+			3 => (0., x, 1.),
+			4 => (x, 0., 1.),
+			_ => (1., 0., x),
+		};
+
+		let apply =
+			|v: Float| ((v * (1. - whiteness - blackness) + whiteness) * 255.).round() as u8;
+		let red = apply(r_prime);
+		let green = apply(g_prime);
+		let blue = apply(b_prime);
+
+		Rgb { red, green, blue }
+	}
+
+	fn to_cmyk(self) -> Cmyk {
+		self.to_rgb().to_cmyk()
+	}
+
+	fn to_hsl(self) -> Hsl {
+		self.to_rgb().to_hsl()
+	}
+
+	fn to_hsv(self) -> Hsv {
+		self.to_rgb().to_hsv()
+	}
+
+	fn to_hwb(self) -> Hwb {
+		self
+	}
+
+	fn to_xyz(self) -> Xyz {
+		self.to_rgb().to_xyz()
+	}
+
+	fn to_lab(self) -> Lab {
+		self.to_rgb().to_lab()
+	}
+
+	fn to_hex_string(self) -> String {
+		self.to_rgb().to_hex_string()
+	}
+
+	fn to_css(self) -> String {
+		// CSS only defines the space-separated syntax for hwb()
+		format!("hwb({} {}% {}%)", self.hue, self.whiteness, self.blackness)
+	}
+}
+
+impl Invert for Hwb {
+	fn invert(self) -> Hwb {
+		Hwb::new_unchecked((self.hue + 180) % 360, self.blackness, self.whiteness)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_to_string() {
+		let hwb = Hwb::new_unchecked(180, 0, 0);
+		assert_eq!(hwb.to_string(), String::from("hwb(180°, 0%, 0%)"));
+	}
+
+	#[test]
+	fn test_to_rgb() {
+		assert_eq!(Hwb::new_unchecked(0, 0, 0).to_rgb(), Rgb::new(255, 0, 0));
+		assert_eq!(
+			Hwb::new_unchecked(180, 0, 0).to_rgb(),
+			Rgb::new(0, 255, 255)
+		);
+		assert_eq!(
+			Hwb::new_unchecked(30, 40, 20).to_rgb(),
+			Rgb::new(204, 153, 102)
+		);
+	}
+
+	#[test]
+	fn test_to_rgb_normalized() {
+		assert_eq!(
+			Hwb::new_unchecked(0, 80, 40).to_rgb(),
+			Rgb::new(170, 170, 170)
+		);
+		assert_eq!(
+			Hwb::new_unchecked(120, 100, 100).to_rgb(),
+			Rgb::new(128, 128, 128)
+		);
+	}
+
+	#[test]
+	fn test_from_rgb() {
+		assert_eq!(Rgb::new(255, 0, 0).to_hwb(), Hwb::new_unchecked(0, 0, 0));
+		assert_eq!(
+			Rgb::new(0, 255, 255).to_hwb(),
+			Hwb::new_unchecked(180, 0, 0)
+		);
+		assert_eq!(
+			Rgb::new(204, 153, 102).to_hwb(),
+			Hwb::new_unchecked(30, 40, 20)
+		);
+		assert_eq!(
+			Rgb::new(128, 128, 128).to_hwb(),
+			Hwb::new_unchecked(0, 50, 50)
+		);
+	}
+
+	#[test]
+	fn test_rgb_round_trip() {
+		for rgb in [
+			Rgb::new(255, 0, 0),
+			Rgb::new(0, 255, 255),
+			Rgb::new(204, 153, 102),
+			Rgb::new(255, 255, 255),
+			Rgb::new(0, 0, 0),
+		]
+		.iter()
+		{
+			assert_eq!(rgb.to_hwb().to_rgb(), *rgb);
+		}
+	}
+
+	#[test]
+	fn test_invert() {
+		let black = Hwb::new_unchecked(0, 0, 100);
+		let white = Hwb::new_unchecked(0, 100, 0);
+		assert_eq!(black.invert().to_rgb(), white.to_rgb());
+		assert_eq!(white.invert().to_rgb(), black.to_rgb());
+		assert_eq!(
+			Rgb::new(204, 153, 102).to_hwb().invert().to_rgb(),
+			Rgb::new(51, 102, 153)
+		);
+	}
+
+	#[should_panic]
+	#[test]
+	fn test_checked_hwb() {
+		Hwb::new(361, 101, 101).unwrap();
+	}
+
+	#[test]
+	fn test_to_css() {
+		let hwb = Hwb::new_unchecked(180, 20, 30);
+		assert_eq!(hwb.to_css(), String::from("hwb(180 20% 30%)"));
+	}
+
+	#[test]
+	fn test_to_tuple() {
+		let hwb = Hwb::new_unchecked(180, 20, 30);
+		assert_eq!(Hwb::try_from(hwb.to_tuple()).unwrap(), hwb);
+	}
+}
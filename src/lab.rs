@@ -0,0 +1,353 @@
+use crate::{prelude::*, xyz::D65};
+use core::fmt;
+
+///
+/// A representation of the CIELAB (L\*a\*b\*) color space, relative to the
+/// D65 white point.
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lab {
+	/// Perceptual lightness, from 0 (black) to 100 (white)
+	pub l: f64,
+	/// Position between green (negative) and red (positive)
+	pub a: f64,
+	/// Position between blue (negative) and yellow (positive)
+	pub b: f64,
+}
+
+impl Lab {
+	///
+	/// Returns a new Lab object given lightness, a, and b values.
+	///
+	/// # Arguments
+	///
+	/// * `l` - the perceptual lightness of the color
+	/// * `a` - the green-red component of the color
+	/// * `b` - the blue-yellow component of the color
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Lab;
+	/// let white = Lab::new(100., 0., 0.);
+	/// ```
+	///
+	pub const fn new(l: f64, a: f64, b: f64) -> Self {
+		Self { l, a, b }
+	}
+
+	///
+	/// Returns the CIE76 color difference between `self` and `other`, which
+	/// is the Euclidean distance between the two colors in Lab space. A
+	/// difference of around 2.3 corresponds to a just-noticeable difference.
+	///
+	/// # Arguments
+	///
+	/// * `other` - the color to compare against
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Lab;
+	///
+	/// let gray = Lab::new(50., 0., 0.);
+	/// let lighter_gray = Lab::new(53., 4., 0.);
+	///
+	/// assert_eq!(gray.delta_e_76(lighter_gray), 5.);
+	/// ```
+	///
+	pub fn delta_e_76(self, other: Lab) -> f64 {
+		((self.l - other.l).powi(2) + (self.a - other.a).powi(2) + (self.b - other.b).powi(2))
+			.sqrt()
+	}
+
+	///
+	/// Returns the CIEDE2000 color difference between `self` and `other`.
+	/// This corrects CIE76 for the non-uniformities of Lab space, so equal
+	/// differences look roughly equally different regardless of hue,
+	/// chroma, or lightness. Identical colors have a difference of `0.0`.
+	///
+	/// # Arguments
+	///
+	/// * `other` - the color to compare against
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Lab;
+	///
+	/// let first = Lab::new(50., 2.6772, -79.7751);
+	/// let second = Lab::new(50., 0., -82.7485);
+	///
+	/// assert!((first.delta_e(second) - 2.0425).abs() < 1e-4);
+	/// ```
+	///
+	pub fn delta_e(self, other: Lab) -> f64 {
+		let pow_25_7 = 25f64.powi(7);
+		let hue = |b: f64, a: f64| {
+			if a == 0. && b == 0. {
+				0.
+			} else {
+				b.atan2(a).to_degrees().rem_euclid(360.)
+			}
+		};
+
+		// Adjust a* to compensate for the non-uniformity of neutral colors
+		let c_bar = (self.a.hypot(self.b) + other.a.hypot(other.b)) / 2.;
+		let g = 0.5 * (1. - (c_bar.powi(7) / (c_bar.powi(7) + pow_25_7)).sqrt());
+		let (a1, a2) = ((1. + g) * self.a, (1. + g) * other.a);
+
+		let (c1, c2) = (a1.hypot(self.b), a2.hypot(other.b));
+		let (h1, h2) = (hue(self.b, a1), hue(other.b, a2));
+
+		let delta_l = other.l - self.l;
+		let delta_c = c2 - c1;
+		let delta_h = if c1 * c2 == 0. {
+			0.
+		} else if (h2 - h1).abs() <= 180. {
+			h2 - h1
+		} else if h2 - h1 > 180. {
+			h2 - h1 - 360.
+		} else {
+			h2 - h1 + 360.
+		};
+		let delta_big_h = 2. * (c1 * c2).sqrt() * (delta_h / 2.).to_radians().sin();
+
+		let l_bar = (self.l + other.l) / 2.;
+		let c_bar = (c1 + c2) / 2.;
+		let h_bar = if c1 * c2 == 0. {
+			h1 + h2
+		} else if (h1 - h2).abs() <= 180. {
+			(h1 + h2) / 2.
+		} else if h1 + h2 < 360. {
+			(h1 + h2 + 360.) / 2.
+		} else {
+			(h1 + h2 - 360.) / 2.
+		};
+
+		let t = 1. - 0.17 * (h_bar - 30.).to_radians().cos()
+			+ 0.24 * (2. * h_bar).to_radians().cos()
+			+ 0.32 * (3. * h_bar + 6.).to_radians().cos()
+			- 0.2 * (4. * h_bar - 63.).to_radians().cos();
+
+		let delta_theta = 30. * (-((h_bar - 275.) / 25.).powi(2)).exp();
+		let r_c = 2. * (c_bar.powi(7) / (c_bar.powi(7) + pow_25_7)).sqrt();
+		let s_l = 1. + (0.015 * (l_bar - 50.).powi(2)) / (20. + (l_bar - 50.).powi(2)).sqrt();
+		let s_c = 1. + 0.045 * c_bar;
+		let s_h = 1. + 0.015 * c_bar * t;
+		let r_t = -(2. * delta_theta).to_radians().sin() * r_c;
+
+		let (l_term, c_term, h_term) = (delta_l / s_l, delta_c / s_c, delta_big_h / s_h);
+
+		(l_term.powi(2) + c_term.powi(2) + h_term.powi(2) + r_t * c_term * h_term).sqrt()
+	}
+}
+
+impl fmt::Display for Lab {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "lab({:.2}, {:.2}, {:.2})", self.l, self.a, self.b)
+	}
+}
+
+impl Color for Lab {
+	fn to_rgb(self) -> Rgb {
+		self.to_xyz().to_rgb()
+	}
+
+	fn to_cmyk(self) -> Cmyk {
+		self.to_rgb().to_cmyk()
+	}
+
+	fn to_hsl(self) -> Hsl {
+		self.to_rgb().to_hsl()
+	}
+
+	fn to_hsv(self) -> Hsv {
+		self.to_rgb().to_hsv()
+	}
+
+	fn to_xyz(self) -> Xyz {
+		let delta = 6f64 / 29.;
+		let f_inv = |t: f64| {
+			if t > delta {
+				t.powi(3)
+			} else {
+				3. * delta.powi(2) * (t - 4. / 29.)
+			}
+		};
+
+		let fy = (self.l + 16.) / 116.;
+		let fx = fy + self.a / 500.;
+		let fz = fy - self.b / 200.;
+
+		Xyz::new(D65.x * f_inv(fx), D65.y * f_inv(fy), D65.z * f_inv(fz))
+	}
+
+	fn to_lab(self) -> Lab {
+		self
+	}
+
+	fn to_hex_string(self) -> String {
+		self.to_rgb().to_hex_string()
+	}
+
+	fn to_hwb(self) -> Hwb {
+		self.to_rgb().to_hwb()
+	}
+
+	fn to_css(self) -> String {
+		// CSS only defines the space-separated syntax for lab()
+		format!("lab({:.2}% {:.2} {:.2})", self.l, self.a, self.b)
+	}
+}
+
+impl Invert for Lab {
+	fn invert(self) -> Lab {
+		self.to_rgb().invert().to_lab()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	fn assert_close(actual: Lab, expected: Lab) {
+		assert!(
+			(actual.l - expected.l).abs() < 0.01,
+			"{} != {}",
+			actual,
+			expected
+		);
+		assert!(
+			(actual.a - expected.a).abs() < 0.01,
+			"{} != {}",
+			actual,
+			expected
+		);
+		assert!(
+			(actual.b - expected.b).abs() < 0.01,
+			"{} != {}",
+			actual,
+			expected
+		);
+	}
+
+	#[test]
+	fn test_to_string() {
+		let lab = Lab::new(53.585, 0., 0.);
+		assert_eq!(lab.to_string(), String::from("lab(53.59, 0.00, 0.00)"));
+	}
+
+	#[test]
+	fn test_from_rgb() {
+		assert_close(Rgb::new(255, 255, 255).to_lab(), Lab::new(100., 0., 0.));
+		assert_close(Rgb::new(128, 128, 128).to_lab(), Lab::new(53.585, 0., 0.));
+		assert_close(
+			Rgb::new(255, 0, 0).to_lab(),
+			Lab::new(53.241, 80.092, 67.203),
+		);
+	}
+
+	#[test]
+	fn test_to_xyz() {
+		let xyz = Lab::new(100., 0., 0.).to_xyz();
+		assert!((xyz.x - D65.x).abs() < 0.01);
+		assert!((xyz.y - D65.y).abs() < 0.01);
+		assert!((xyz.z - D65.z).abs() < 0.01);
+	}
+
+	#[test]
+	fn test_rgb_round_trip() {
+		for rgb in [
+			Rgb::new(30, 50, 60),
+			Rgb::new(204, 153, 102),
+			Rgb::new(0, 0, 0),
+			Rgb::new(255, 0, 0),
+		]
+		.iter()
+		{
+			assert_eq!(rgb.to_lab().to_rgb(), *rgb);
+		}
+	}
+
+	#[test]
+	fn test_delta_e_76() {
+		let lab = Lab::new(50., 2.5, 0.);
+		assert_eq!(lab.delta_e_76(lab), 0.);
+		assert!((lab.delta_e_76(Lab::new(53., -1.5, 0.)) - 5.).abs() < 1e-9);
+	}
+
+	#[test]
+	fn test_delta_e_identical() {
+		let lab = Rgb::new(30, 50, 60).to_lab();
+		assert_eq!(lab.delta_e(lab), 0.);
+	}
+
+	#[test]
+	fn test_delta_e_symmetric() {
+		let first = Lab::new(50., 2.5, 0.);
+		let second = Lab::new(73., 25., -18.);
+		assert!((first.delta_e(second) - second.delta_e(first)).abs() < 1e-9);
+	}
+
+	/// Reference data from Sharma, Wu, and Dalal, "The CIEDE2000
+	/// Color-Difference Formula: Implementation Notes, Supplementary Test
+	/// Data, and Mathematical Observations" (2005)
+	#[rustfmt::skip]
+	const SHARMA: [(f64, f64, f64, f64, f64, f64, f64); 34] = [
+		(50.0000, 2.6772, -79.7751, 50.0000, 0.0000, -82.7485, 2.0425),
+		(50.0000, 3.1571, -77.2803, 50.0000, 0.0000, -82.7485, 2.8615),
+		(50.0000, 2.8361, -74.0200, 50.0000, 0.0000, -82.7485, 3.4412),
+		(50.0000, -1.3802, -84.2814, 50.0000, 0.0000, -82.7485, 1.0000),
+		(50.0000, -1.1848, -84.8006, 50.0000, 0.0000, -82.7485, 1.0000),
+		(50.0000, -0.9009, -85.5211, 50.0000, 0.0000, -82.7485, 1.0000),
+		(50.0000, 0.0000, 0.0000, 50.0000, -1.0000, 2.0000, 2.3669),
+		(50.0000, -1.0000, 2.0000, 50.0000, 0.0000, 0.0000, 2.3669),
+		(50.0000, 2.4900, -0.0010, 50.0000, -2.4900, 0.0009, 7.1792),
+		(50.0000, 2.4900, -0.0010, 50.0000, -2.4900, 0.0010, 7.1792),
+		(50.0000, 2.4900, -0.0010, 50.0000, -2.4900, 0.0011, 7.2195),
+		(50.0000, 2.4900, -0.0010, 50.0000, -2.4900, 0.0012, 7.2195),
+		(50.0000, -0.0010, 2.4900, 50.0000, 0.0009, -2.4900, 4.8045),
+		(50.0000, -0.0010, 2.4900, 50.0000, 0.0010, -2.4900, 4.8045),
+		(50.0000, -0.0010, 2.4900, 50.0000, 0.0011, -2.4900, 4.7461),
+		(50.0000, 2.5000, 0.0000, 50.0000, 0.0000, -2.5000, 4.3065),
+		(50.0000, 2.5000, 0.0000, 73.0000, 25.0000, -18.0000, 27.1492),
+		(50.0000, 2.5000, 0.0000, 61.0000, -5.0000, 29.0000, 22.8977),
+		(50.0000, 2.5000, 0.0000, 56.0000, -27.0000, -3.0000, 31.9030),
+		(50.0000, 2.5000, 0.0000, 58.0000, 24.0000, 15.0000, 19.4535),
+		(50.0000, 2.5000, 0.0000, 50.0000, 3.1736, 0.5854, 1.0000),
+		(50.0000, 2.5000, 0.0000, 50.0000, 3.2972, 0.0000, 1.0000),
+		(50.0000, 2.5000, 0.0000, 50.0000, 1.8634, 0.5757, 1.0000),
+		(50.0000, 2.5000, 0.0000, 50.0000, 3.2592, 0.3350, 1.0000),
+		(60.2574, -34.0099, 36.2677, 60.4626, -34.1751, 39.4387, 1.2644),
+		(63.0109, -31.0961, -5.8663, 62.8187, -29.7946, -4.0864, 1.2630),
+		(61.2901, 3.7196, -5.3901, 61.4292, 2.2480, -4.9620, 1.8731),
+		(35.0831, -44.1164, 3.7933, 35.0232, -40.0716, 1.5901, 1.8645),
+		(22.7233, 20.0904, -46.6940, 23.0331, 14.9730, -42.5619, 2.0373),
+		(36.4612, 47.8580, 18.3852, 36.2715, 50.5065, 21.2231, 1.4146),
+		(90.8027, -2.0831, 1.4410, 91.1528, -1.6435, 0.0447, 1.4441),
+		(90.9257, -0.5406, -0.9208, 88.6381, -0.8985, -0.7239, 1.5381),
+		(6.7747, -0.2908, -2.4247, 5.8714, -0.0985, -2.2286, 0.6377),
+		(2.0776, 0.0795, -1.1350, 0.9033, -0.0636, -0.5514, 0.9082),
+	];
+
+	#[test]
+	fn test_delta_e_sharma() {
+		for (l1, a1, b1, l2, a2, b2, expected) in SHARMA.iter() {
+			let delta_e = Lab::new(*l1, *a1, *b1).delta_e(Lab::new(*l2, *a2, *b2));
+			assert!(
+				(delta_e - expected).abs() < 1e-4,
+				"{} != {}",
+				delta_e,
+				expected
+			);
+		}
+	}
+
+	#[test]
+	fn test_to_css() {
+		let lab = Lab::new(53.2408, 80.0925, 67.2032);
+		assert_eq!(lab.to_css(), String::from("lab(53.24% 80.09 67.20)"));
+	}
+}
@@ -4,10 +4,12 @@
 	trivial_casts,
 	trivial_numeric_casts
 )]
+#![cfg_attr(not(feature = "std"), no_std)]
 
 //!
 //! `color_conv` is a helper library for easily and programmatically converting
-//! between the `RGB`, `CMYK`, `HSL`, and `hex` color formats.
+//! between the `RGB`, `CMY`, `CMYK`, `HSL`, `HSV`, `HWB`, `CIE XYZ`, `CIELAB`,
+//! `YCbCr`, and `hex` color formats.
 //!
 //! ```toml
 //! [dependencies]
@@ -27,38 +29,121 @@
 //! assert_eq!(Rgb::new(0, 255, 255), cyan_rgb);
 //! ```
 //!
+//! # Features
+//!
+//! * `std` (default) - links against the standard library. Disabling it
+//!   makes the crate `#![no_std]`: it still requires [`alloc`] for
+//!   [`String`](alloc::string::String) and [`Vec`](alloc::vec::Vec), and
+//!   floating-point math falls back to [`libm`].
+//! * `f32` - performs conversion math using `f32` instead of `f64` (see
+//!   [`Float`]). This halves the size of intermediate values, which can help
+//!   on memory-constrained or embedded targets, at the cost of precision:
+//!   values that land close to a rounding boundary may round differently
+//!   than they would with `f64`.
+//! * `serde` - derives `Serialize` and `Deserialize` for each of the color
+//!   types and provides the `serde_hex` module for (de)serializing an
+//!   [`Rgb`] as a hex string.
+//! * `rand` - adds `Rgb::random` and `Hsl::random_vivid` for generating
+//!   random colors from any `rand::Rng`.
+//! * `testing` - exposes the `testing` module, which encodes the round-trip
+//!   precision the crate guarantees as assertions and constants for use in
+//!   downstream tests.
+//! * `simd` - processes [`convert_rgb_to_hsl_bulk`] in fixed-width chunks
+//!   laid out for auto-vectorization. `std::simd` is still unstable, so this
+//!   relies on the compiler rather than explicit intrinsics. The results are
+//!   identical with or without it.
+//!
 
+extern crate alloc;
+
+/// CMY-specific structures
+pub mod cmy;
 /// CMYK-specific structures
 pub mod cmyk;
+/// Crate-wide error type
+pub mod error;
 /// HSL-specific strucures
 pub mod hsl;
+/// HSLA-specific structures
+pub mod hsla;
+/// HSV-specific strucures
+pub mod hsv;
+/// HWB-specific strucures
+pub mod hwb;
+/// CIELAB-specific strucures
+pub mod lab;
+///
+/// Typed constants for every CSS Color Module Level 4 named color, usable
+/// without any string lookup or error handling.
+///
+/// ```
+/// use color_conv::{named, Rgb};
+///
+/// assert_eq!(named::REBECCA_PURPLE, Rgb::new(102, 51, 153));
+/// ```
+///
+pub mod named;
 /// RGB-specific strucures
 pub mod rgb;
+/// RGBA-specific strucures
+pub mod rgba;
+/// CIE XYZ-specific strucures
+pub mod xyz;
+/// YCbCr-specific structures
+pub mod ycbcr;
 
-pub use self::{cmyk::Cmyk, hsl::Hsl, rgb::Rgb};
-use thiserror::Error as ThisError;
+/// Commonly used items, for glob importing
+pub mod prelude;
+
+/// Hex string (de)serialization for [`Rgb`]
+#[cfg(feature = "serde")]
+pub mod serde_hex;
+
+/// Round-trip invariants and the precision the crate guarantees
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+
+mod bulk;
+mod conversion;
+mod css;
+#[cfg(not(any(feature = "std", test)))]
+mod float;
+mod macros;
+mod names;
+mod parse;
 
-#[derive(ThisError, Debug)]
 ///
-/// Crate-wide Error type.
+/// The floating-point type used for conversion math. This is `f64` by
+/// default, or `f32` when the `f32` feature is enabled.
 ///
-pub enum Error {
-	///
-	/// Occurs when a parameter representing a percentage value is greater than
-	/// 100. This error can be thrown by [`Cmyk::new`](crate::Cmyk::new) or
-	/// [`Hsl::new`](crate::Hsl::new), both of which perform this check.
-	///
-	#[error("Percentage overflow: value is larger than 100!")]
-	PercentageOverflow,
-	///
-	/// Occurs when a parameter representing a degree value is greater than 360.
-	/// 100. This error can be thrown by  [`Hsl::new`](crate::Hsl::new), which
-	/// performs this check.
-	///
-	#[error("Degree overflow: value is larger than 360!")]
-	DegreeOverflow,
-}
+#[cfg(not(feature = "f32"))]
+pub type Float = f64;
+///
+/// The floating-point type used for conversion math. This is `f64` by
+/// default, or `f32` when the `f32` feature is enabled.
+///
+#[cfg(feature = "f32")]
+pub type Float = f32;
+
+use alloc::string::{String, ToString};
 
+pub use self::{
+	bulk::convert_rgb_to_hsl_bulk,
+	cmy::Cmy,
+	cmyk::{Cmyk, CmykBuilder},
+	conversion::{AllFormats, Format, FormattedColor},
+	error::Error,
+	hsl::{Hsl, HslBuilder},
+	hsla::Hsla,
+	hsv::Hsv,
+	hwb::Hwb,
+	lab::Lab,
+	parse::parse_any,
+	rgb::{Channel, CvdType, GrayscaleMethod, HexOptions, Rgb},
+	rgba::Rgba,
+	xyz::Xyz,
+	ycbcr::{YCbCr, YCbCrMatrix, YCbCrRange},
+};
 ///
 /// Unifying `Color` trait which encompasses each of the structs provided by
 /// this crate.
@@ -121,6 +206,76 @@ pub trait Color {
 	///
 	fn to_hsl(self) -> Hsl;
 
+	///
+	/// Convert to [`Hsv`]
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Color;
+	/// use color_conv::Hsv;
+	/// use color_conv::Rgb;
+	///
+	/// let cyan = Rgb::new(0, 255, 255);
+	/// let cyan_hsv = cyan.to_hsv();
+	///
+	/// assert_eq!(cyan_hsv, Hsv::new_unchecked(180, 100, 100));
+	/// ```
+	///
+	fn to_hsv(self) -> Hsv;
+
+	///
+	/// Convert to [`Hwb`]
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Color;
+	/// use color_conv::Hwb;
+	/// use color_conv::Rgb;
+	///
+	/// let cyan = Rgb::new(0, 255, 255);
+	/// let cyan_hwb = cyan.to_hwb();
+	///
+	/// assert_eq!(cyan_hwb, Hwb::new_unchecked(180, 0, 0));
+	/// ```
+	///
+	fn to_hwb(self) -> Hwb;
+
+	///
+	/// Convert to [`Xyz`], using the D65 white point
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Color;
+	/// use color_conv::Rgb;
+	///
+	/// let white = Rgb::new(255, 255, 255);
+	/// let white_xyz = white.to_xyz();
+	///
+	/// assert!((white_xyz.y - 100.).abs() < 0.01);
+	/// ```
+	///
+	fn to_xyz(self) -> Xyz;
+
+	///
+	/// Convert to [`Lab`], using the D65 white point
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Color;
+	/// use color_conv::Rgb;
+	///
+	/// let white = Rgb::new(255, 255, 255);
+	/// let white_lab = white.to_lab();
+	///
+	/// assert!((white_lab.l - 100.).abs() < 0.01);
+	/// ```
+	///
+	fn to_lab(self) -> Lab;
+
 	///
 	/// Convert to a [`String`] containing the hex code of the color prefixed
 	/// with a hashtag (`#`)
@@ -138,4 +293,320 @@ pub trait Color {
 	/// ```
 	///
 	fn to_hex_string(self) -> String;
+
+	///
+	/// Convert to a [`String`] containing the color in CSS notation. Formats
+	/// that CSS supports, such as [`Hsl`] and [`Lab`], use their own
+	/// functional notation, while formats that it doesn't, such as [`Cmyk`],
+	/// fall back to `rgb(...)`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Color;
+	/// use color_conv::Cmyk;
+	/// use color_conv::Hsl;
+	///
+	/// let cyan = Hsl::new_unchecked(180, 100, 50);
+	/// assert_eq!(cyan.to_css(), String::from("hsl(180, 100%, 50%)"));
+	///
+	/// let cyan = Cmyk::new_unchecked(100, 0, 0, 0);
+	/// assert_eq!(cyan.to_css(), String::from("rgb(0, 255, 255)"));
+	/// ```
+	///
+	fn to_css(self) -> String
+	where
+		Self: Sized,
+	{
+		self.to_rgb().to_string()
+	}
+
+	///
+	/// Blends two colors of any type by converting both to [`Rgb`] and
+	/// interpolating between them with [`Rgb::lerp`]. A `t` of `0.0` returns
+	/// `self` and a `t` of `1.0` returns `other`.
+	///
+	/// # Arguments
+	///
+	/// * `other` - the color to blend towards
+	/// * `t` - the interpolation factor, clamped to the range `[0.0, 1.0]`
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Color;
+	/// use color_conv::Cmyk;
+	/// use color_conv::Hsl;
+	/// use color_conv::Rgb;
+	///
+	/// let black = Cmyk::new_unchecked(0, 0, 0, 100);
+	/// let white = Hsl::new_unchecked(0, 0, 100);
+	///
+	/// assert_eq!(black.blend(white, 0.5), Rgb::new(128, 128, 128));
+	/// ```
+	///
+	fn blend(self, other: impl Color, t: f64) -> Rgb
+	where
+		Self: Sized,
+	{
+		self.to_rgb().lerp(other.to_rgb(), t)
+	}
+
+	///
+	/// Converts the color to [`Rgb`] and returns its channels as bytes
+	/// ordered R, G, B, so that any color can be written out generically.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Color;
+	/// use color_conv::Cmyk;
+	///
+	/// let cyan = Cmyk::new_unchecked(100, 0, 0, 0);
+	/// assert_eq!(cyan.as_rgb_array(), [0, 255, 255]);
+	/// ```
+	///
+	// Every color type is `Copy`, so taking `self` by value is cheap
+	#[allow(clippy::wrong_self_convention)]
+	fn as_rgb_array(self) -> [u8; 3]
+	where
+		Self: Sized,
+	{
+		self.to_rgb().to_bytes()
+	}
+
+	///
+	/// Returns whether the color is a shade of gray, meaning that all three
+	/// channels of its [`Rgb`] representation are equal.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Color;
+	/// use color_conv::Hsl;
+	/// use color_conv::Rgb;
+	///
+	/// assert!(Rgb::new(128, 128, 128).is_grayscale());
+	/// assert!(!Hsl::new_unchecked(180, 100, 50).is_grayscale());
+	/// ```
+	///
+	// Every color type is `Copy`, so taking `self` by value is cheap
+	#[allow(clippy::wrong_self_convention)]
+	fn is_grayscale(self) -> bool
+	where
+		Self: Sized,
+	{
+		let Rgb { red, green, blue } = self.to_rgb();
+		red == green && green == blue
+	}
+
+	///
+	/// Converts the color to a [`Format`] chosen at runtime, returning the
+	/// converted value wrapped in a [`FormattedColor`], whose `to_string`
+	/// gives its usual formatted form.
+	///
+	/// # Arguments
+	///
+	/// * `target` - the format to convert to
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Color;
+	/// use color_conv::Format;
+	/// use color_conv::Hsl;
+	///
+	/// let cyan = Hsl::new_unchecked(180, 100, 50);
+	/// let target: Format = "hex".parse()?;
+	///
+	/// assert_eq!(cyan.convert_to(target).to_string(), String::from("#00ffff"));
+	/// # Ok::<(), color_conv::Error>(())
+	/// ```
+	///
+	fn convert_to(self, target: Format) -> FormattedColor
+	where
+		Self: Sized,
+	{
+		match target {
+			Format::Rgb => FormattedColor::Rgb(self.to_rgb()),
+			Format::Cmyk => FormattedColor::Cmyk(self.to_cmyk()),
+			Format::Hsl => FormattedColor::Hsl(self.to_hsl()),
+			Format::Hex => FormattedColor::Hex(self.to_hex_string()),
+		}
+	}
+
+	///
+	/// Converts the color to every [`Format`] at once, returning an
+	/// [`AllFormats`]. The color is converted to [`Rgb`] only once, and the
+	/// other formats are derived from that.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Color;
+	/// use color_conv::Cmyk;
+	/// use color_conv::Hsl;
+	/// use color_conv::Rgb;
+	///
+	/// let all = Hsl::new_unchecked(180, 100, 50).to_all();
+	///
+	/// assert_eq!(all.rgb, Rgb::new(0, 255, 255));
+	/// assert_eq!(all.cmyk, Cmyk::new_unchecked(100, 0, 0, 0));
+	/// assert_eq!(all.hsl, Hsl::new_unchecked(180, 100, 50));
+	/// assert_eq!(all.hex, String::from("#00ffff"));
+	/// ```
+	///
+	fn to_all(self) -> AllFormats
+	where
+		Self: Sized,
+	{
+		let rgb = self.to_rgb();
+
+		AllFormats {
+			rgb,
+			cmyk: rgb.to_cmyk(),
+			hsl: rgb.to_hsl(),
+			hex: rgb.to_hex_string(),
+		}
+	}
+
+	///
+	/// Returns the Euclidean distance between two colors in RGB space,
+	/// ranging from `0.0` for identical colors to roughly `441.67` for black
+	/// and white. This is cheap to compute but not perceptually uniform; see
+	/// [`Lab::delta_e`] for a perceptual alternative.
+	///
+	/// # Arguments
+	///
+	/// * `other` - the color to measure the distance to
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Color;
+	/// use color_conv::Rgb;
+	///
+	/// let black = Rgb::new(0, 0, 0);
+	/// let blue = Rgb::new(0, 0, 255);
+	///
+	/// assert_eq!(black.distance(blue), 255.);
+	/// ```
+	///
+	fn distance(self, other: Self) -> f64
+	where
+		Self: Sized,
+	{
+		rgb::distance(self.to_rgb(), other.to_rgb())
+	}
+}
+
+///
+/// Color inversion, implemented by each of the structs provided by this
+/// crate. This is kept separate from [`Color`] so that implementing [`Color`]
+/// for a custom type doesn't also require implementing inversion.
+///
+pub trait Invert {
+	///
+	/// Invert the color, returning a value of the same type. Inverting black
+	/// gives white and vice versa.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Invert;
+	/// use color_conv::Rgb;
+	///
+	/// let cyan = Rgb::new(0, 255, 255);
+	/// let red = cyan.invert();
+	///
+	/// assert_eq!(red, Rgb::new(255, 0, 0));
+	/// ```
+	///
+	fn invert(self) -> Self;
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_float_width() {
+		let expected = if cfg!(feature = "f32") { 4 } else { 8 };
+		assert_eq!(core::mem::size_of::<Float>(), expected);
+	}
+
+	#[test]
+	fn test_conversions_under_float_width() {
+		let rgb = Rgb::new(204, 153, 102);
+
+		assert_eq!(rgb.to_hsl(), Hsl::new_unchecked(30, 50, 60));
+		assert_eq!(rgb.to_hsv(), Hsv::new_unchecked(30, 50, 80));
+		assert_eq!(rgb.to_hsl().to_rgb(), rgb);
+		assert_eq!(rgb.to_hsv().to_rgb(), rgb);
+
+		let cmyk = Rgb::new(30, 50, 60).to_cmyk();
+		assert_eq!(cmyk, Cmyk::new_unchecked(50, 17, 0, 76));
+		assert_eq!(cmyk.to_rgb(), Rgb::new(31, 51, 61));
+	}
+
+	#[test]
+	fn test_const_constructors() {
+		const CYAN: Rgb = Rgb::new(0, 255, 255);
+		const CYAN_RGBA: Rgba = Rgba::new(0, 255, 255, 255);
+		const CYAN_CMYK: Cmyk = Cmyk::new_unchecked(100, 0, 0, 0);
+		const CYAN_HSL: Hsl = Hsl::new_unchecked(180, 100, 50);
+		const CYAN_HSV: Hsv = Hsv::new_unchecked(180, 100, 100);
+		const CYAN_HWB: Hwb = Hwb::new_unchecked(180, 0, 0);
+		const WHITE_XYZ: Xyz = Xyz::new(95.047, 100., 108.883);
+		const WHITE_LAB: Lab = Lab::new(100., 0., 0.);
+
+		assert_eq!(CYAN_RGBA.to_rgb(), CYAN);
+		assert_eq!(CYAN_CMYK.to_rgb(), CYAN);
+		assert_eq!(CYAN_HSL.to_rgb(), CYAN);
+		assert_eq!(CYAN_HSV.to_rgb(), CYAN);
+		assert_eq!(CYAN_HWB.to_rgb(), CYAN);
+		assert_eq!(WHITE_XYZ.to_rgb(), Rgb::new(255, 255, 255));
+		assert_eq!(WHITE_LAB.to_rgb(), Rgb::new(255, 255, 255));
+	}
+
+	#[test]
+	fn test_blend_mixed_types() {
+		let cyan = Cmyk::new_unchecked(100, 0, 0, 0);
+		let red = Hsl::new_unchecked(0, 100, 50);
+
+		assert_eq!(cyan.blend(red, 0.), Rgb::new(0, 255, 255));
+		assert_eq!(cyan.blend(red, 1.), Rgb::new(255, 0, 0));
+		assert_eq!(cyan.blend(red, 0.5), Rgb::new(128, 128, 128));
+		assert_eq!(red.blend(cyan, 0.25), Rgb::new(191, 64, 64));
+
+		let black = Hsv::new_unchecked(0, 0, 0);
+		let white = Rgba::new(255, 255, 255, 255);
+		assert_eq!(black.blend(white, 0.5), Rgb::new(128, 128, 128));
+	}
+
+	#[test]
+	fn test_as_rgb_array() {
+		assert_eq!(Rgb::new(30, 50, 60).as_rgb_array(), [30, 50, 60]);
+		assert_eq!(
+			Hsl::new_unchecked(180, 100, 50).as_rgb_array(),
+			[0, 255, 255]
+		);
+		assert_eq!(
+			Cmyk::new_unchecked(0, 100, 100, 0).as_rgb_array(),
+			[255, 0, 0]
+		);
+		assert_eq!(Cmyk::new_unchecked(0, 0, 0, 100).as_rgb_array(), [0, 0, 0]);
+	}
+
+	#[test]
+	fn test_is_grayscale() {
+		assert!(Rgb::new(0, 0, 0).is_grayscale());
+		assert!(Rgb::new(128, 128, 128).is_grayscale());
+		assert!(Hsl::new_unchecked(200, 0, 40).is_grayscale());
+		assert!(Cmyk::new_unchecked(0, 0, 0, 50).is_grayscale());
+
+		assert!(!Rgb::new(128, 128, 129).is_grayscale());
+		assert!(!Rgb::new(255, 0, 0).is_grayscale());
+		assert!(!Hsv::new_unchecked(120, 100, 100).is_grayscale());
+	}
 }
@@ -0,0 +1,78 @@
+///
+/// Creates an [`Rgb`](crate::Rgb) from either a packed `0xRRGGBB` integer
+/// or separate red, green, and blue values. Expands to a call to a
+/// `const fn` constructor, so it can be used in `const` position.
+///
+/// # Examples
+///
+/// ```
+/// use color_conv::{rgb, Rgb};
+///
+/// const CYAN: Rgb = rgb!(0x00ffff);
+///
+/// assert_eq!(CYAN, Rgb::new(0, 255, 255));
+/// assert_eq!(rgb!(30, 50, 60), Rgb::new(30, 50, 60));
+/// ```
+///
+#[macro_export]
+macro_rules! rgb {
+	($packed:expr) => {
+		$crate::Rgb::from_u32($packed)
+	};
+	($red:expr, $green:expr, $blue:expr) => {
+		$crate::Rgb::new($red, $green, $blue)
+	};
+}
+
+///
+/// Creates an [`Rgba`](crate::Rgba) from either a packed `0xRRGGBBAA`
+/// integer or separate red, green, blue, and alpha values. Expands to a call
+/// to a `const fn` constructor, so it can be used in `const` position.
+///
+/// # Examples
+///
+/// ```
+/// use color_conv::{rgba, Rgba};
+///
+/// const TRANSLUCENT_CYAN: Rgba = rgba!(0x00ffff80);
+///
+/// assert_eq!(TRANSLUCENT_CYAN, Rgba::new(0, 255, 255, 128));
+/// assert_eq!(rgba!(30, 50, 60, 255), Rgba::new(30, 50, 60, 255));
+/// ```
+///
+#[macro_export]
+macro_rules! rgba {
+	($packed:expr) => {
+		$crate::Rgba::from_u32($packed)
+	};
+	($red:expr, $green:expr, $blue:expr, $alpha:expr) => {
+		$crate::Rgba::new($red, $green, $blue, $alpha)
+	};
+}
+
+#[cfg(test)]
+mod test {
+	use crate::prelude::*;
+
+	const PACKED: Rgb = rgb!(0x1e323c);
+	const CHANNELS: Rgb = rgb!(30, 50, 60);
+	const PACKED_ALPHA: Rgba = rgba!(0x1e323c80);
+	const CHANNELS_ALPHA: Rgba = rgba!(30, 50, 60, 128);
+
+	#[test]
+	fn test_rgb() {
+		assert_eq!(PACKED, Rgb::new(30, 50, 60));
+		assert_eq!(CHANNELS, Rgb::new(30, 50, 60));
+		assert_eq!(rgb!(0xffffff), Rgb::new(255, 255, 255));
+
+		let blue = 255;
+		assert_eq!(rgb!(0, 0, blue), Rgb::new(0, 0, 255));
+	}
+
+	#[test]
+	fn test_rgba() {
+		assert_eq!(PACKED_ALPHA, Rgba::new(30, 50, 60, 128));
+		assert_eq!(CHANNELS_ALPHA, Rgba::new(30, 50, 60, 128));
+		assert_eq!(rgba!(0xffffffff), Rgba::new(255, 255, 255, 255));
+	}
+}
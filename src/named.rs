@@ -0,0 +1,482 @@
+use crate::Rgb;
+
+// Kept in alphabetical order, since the runtime lookup table in `names.rs`
+// is built from these constants
+
+/// CSS `aliceblue` (`#f0f8ff`)
+pub const ALICE_BLUE: Rgb = Rgb::from_u32(0xf0f8ff);
+
+/// CSS `antiquewhite` (`#faebd7`)
+pub const ANTIQUE_WHITE: Rgb = Rgb::from_u32(0xfaebd7);
+
+/// CSS `aqua` (`#00ffff`)
+pub const AQUA: Rgb = Rgb::from_u32(0x00ffff);
+
+/// CSS `aquamarine` (`#7fffd4`)
+pub const AQUAMARINE: Rgb = Rgb::from_u32(0x7fffd4);
+
+/// CSS `azure` (`#f0ffff`)
+pub const AZURE: Rgb = Rgb::from_u32(0xf0ffff);
+
+/// CSS `beige` (`#f5f5dc`)
+pub const BEIGE: Rgb = Rgb::from_u32(0xf5f5dc);
+
+/// CSS `bisque` (`#ffe4c4`)
+pub const BISQUE: Rgb = Rgb::from_u32(0xffe4c4);
+
+/// CSS `black` (`#000000`)
+pub const BLACK: Rgb = Rgb::from_u32(0x000000);
+
+/// CSS `blanchedalmond` (`#ffebcd`)
+pub const BLANCHED_ALMOND: Rgb = Rgb::from_u32(0xffebcd);
+
+/// CSS `blue` (`#0000ff`)
+pub const BLUE: Rgb = Rgb::from_u32(0x0000ff);
+
+/// CSS `blueviolet` (`#8a2be2`)
+pub const BLUE_VIOLET: Rgb = Rgb::from_u32(0x8a2be2);
+
+/// CSS `brown` (`#a52a2a`)
+pub const BROWN: Rgb = Rgb::from_u32(0xa52a2a);
+
+/// CSS `burlywood` (`#deb887`)
+pub const BURLYWOOD: Rgb = Rgb::from_u32(0xdeb887);
+
+/// CSS `cadetblue` (`#5f9ea0`)
+pub const CADET_BLUE: Rgb = Rgb::from_u32(0x5f9ea0);
+
+/// CSS `chartreuse` (`#7fff00`)
+pub const CHARTREUSE: Rgb = Rgb::from_u32(0x7fff00);
+
+/// CSS `chocolate` (`#d2691e`)
+pub const CHOCOLATE: Rgb = Rgb::from_u32(0xd2691e);
+
+/// CSS `coral` (`#ff7f50`)
+pub const CORAL: Rgb = Rgb::from_u32(0xff7f50);
+
+/// CSS `cornflowerblue` (`#6495ed`)
+pub const CORNFLOWER_BLUE: Rgb = Rgb::from_u32(0x6495ed);
+
+/// CSS `cornsilk` (`#fff8dc`)
+pub const CORNSILK: Rgb = Rgb::from_u32(0xfff8dc);
+
+/// CSS `crimson` (`#dc143c`)
+pub const CRIMSON: Rgb = Rgb::from_u32(0xdc143c);
+
+/// CSS `cyan` (`#00ffff`)
+pub const CYAN: Rgb = Rgb::from_u32(0x00ffff);
+
+/// CSS `darkblue` (`#00008b`)
+pub const DARK_BLUE: Rgb = Rgb::from_u32(0x00008b);
+
+/// CSS `darkcyan` (`#008b8b`)
+pub const DARK_CYAN: Rgb = Rgb::from_u32(0x008b8b);
+
+/// CSS `darkgoldenrod` (`#b8860b`)
+pub const DARK_GOLDENROD: Rgb = Rgb::from_u32(0xb8860b);
+
+/// CSS `darkgray` (`#a9a9a9`)
+pub const DARK_GRAY: Rgb = Rgb::from_u32(0xa9a9a9);
+
+/// CSS `darkgreen` (`#006400`)
+pub const DARK_GREEN: Rgb = Rgb::from_u32(0x006400);
+
+/// CSS `darkgrey` (`#a9a9a9`)
+pub const DARK_GREY: Rgb = Rgb::from_u32(0xa9a9a9);
+
+/// CSS `darkkhaki` (`#bdb76b`)
+pub const DARK_KHAKI: Rgb = Rgb::from_u32(0xbdb76b);
+
+/// CSS `darkmagenta` (`#8b008b`)
+pub const DARK_MAGENTA: Rgb = Rgb::from_u32(0x8b008b);
+
+/// CSS `darkolivegreen` (`#556b2f`)
+pub const DARK_OLIVE_GREEN: Rgb = Rgb::from_u32(0x556b2f);
+
+/// CSS `darkorange` (`#ff8c00`)
+pub const DARK_ORANGE: Rgb = Rgb::from_u32(0xff8c00);
+
+/// CSS `darkorchid` (`#9932cc`)
+pub const DARK_ORCHID: Rgb = Rgb::from_u32(0x9932cc);
+
+/// CSS `darkred` (`#8b0000`)
+pub const DARK_RED: Rgb = Rgb::from_u32(0x8b0000);
+
+/// CSS `darksalmon` (`#e9967a`)
+pub const DARK_SALMON: Rgb = Rgb::from_u32(0xe9967a);
+
+/// CSS `darkseagreen` (`#8fbc8f`)
+pub const DARK_SEA_GREEN: Rgb = Rgb::from_u32(0x8fbc8f);
+
+/// CSS `darkslateblue` (`#483d8b`)
+pub const DARK_SLATE_BLUE: Rgb = Rgb::from_u32(0x483d8b);
+
+/// CSS `darkslategray` (`#2f4f4f`)
+pub const DARK_SLATE_GRAY: Rgb = Rgb::from_u32(0x2f4f4f);
+
+/// CSS `darkslategrey` (`#2f4f4f`)
+pub const DARK_SLATE_GREY: Rgb = Rgb::from_u32(0x2f4f4f);
+
+/// CSS `darkturquoise` (`#00ced1`)
+pub const DARK_TURQUOISE: Rgb = Rgb::from_u32(0x00ced1);
+
+/// CSS `darkviolet` (`#9400d3`)
+pub const DARK_VIOLET: Rgb = Rgb::from_u32(0x9400d3);
+
+/// CSS `deeppink` (`#ff1493`)
+pub const DEEP_PINK: Rgb = Rgb::from_u32(0xff1493);
+
+/// CSS `deepskyblue` (`#00bfff`)
+pub const DEEP_SKY_BLUE: Rgb = Rgb::from_u32(0x00bfff);
+
+/// CSS `dimgray` (`#696969`)
+pub const DIM_GRAY: Rgb = Rgb::from_u32(0x696969);
+
+/// CSS `dimgrey` (`#696969`)
+pub const DIM_GREY: Rgb = Rgb::from_u32(0x696969);
+
+/// CSS `dodgerblue` (`#1e90ff`)
+pub const DODGER_BLUE: Rgb = Rgb::from_u32(0x1e90ff);
+
+/// CSS `firebrick` (`#b22222`)
+pub const FIREBRICK: Rgb = Rgb::from_u32(0xb22222);
+
+/// CSS `floralwhite` (`#fffaf0`)
+pub const FLORAL_WHITE: Rgb = Rgb::from_u32(0xfffaf0);
+
+/// CSS `forestgreen` (`#228b22`)
+pub const FOREST_GREEN: Rgb = Rgb::from_u32(0x228b22);
+
+/// CSS `fuchsia` (`#ff00ff`)
+pub const FUCHSIA: Rgb = Rgb::from_u32(0xff00ff);
+
+/// CSS `gainsboro` (`#dcdcdc`)
+pub const GAINSBORO: Rgb = Rgb::from_u32(0xdcdcdc);
+
+/// CSS `ghostwhite` (`#f8f8ff`)
+pub const GHOST_WHITE: Rgb = Rgb::from_u32(0xf8f8ff);
+
+/// CSS `gold` (`#ffd700`)
+pub const GOLD: Rgb = Rgb::from_u32(0xffd700);
+
+/// CSS `goldenrod` (`#daa520`)
+pub const GOLDENROD: Rgb = Rgb::from_u32(0xdaa520);
+
+/// CSS `gray` (`#808080`)
+pub const GRAY: Rgb = Rgb::from_u32(0x808080);
+
+/// CSS `green` (`#008000`)
+pub const GREEN: Rgb = Rgb::from_u32(0x008000);
+
+/// CSS `greenyellow` (`#adff2f`)
+pub const GREEN_YELLOW: Rgb = Rgb::from_u32(0xadff2f);
+
+/// CSS `grey` (`#808080`)
+pub const GREY: Rgb = Rgb::from_u32(0x808080);
+
+/// CSS `honeydew` (`#f0fff0`)
+pub const HONEYDEW: Rgb = Rgb::from_u32(0xf0fff0);
+
+/// CSS `hotpink` (`#ff69b4`)
+pub const HOT_PINK: Rgb = Rgb::from_u32(0xff69b4);
+
+/// CSS `indianred` (`#cd5c5c`)
+pub const INDIAN_RED: Rgb = Rgb::from_u32(0xcd5c5c);
+
+/// CSS `indigo` (`#4b0082`)
+pub const INDIGO: Rgb = Rgb::from_u32(0x4b0082);
+
+/// CSS `ivory` (`#fffff0`)
+pub const IVORY: Rgb = Rgb::from_u32(0xfffff0);
+
+/// CSS `khaki` (`#f0e68c`)
+pub const KHAKI: Rgb = Rgb::from_u32(0xf0e68c);
+
+/// CSS `lavender` (`#e6e6fa`)
+pub const LAVENDER: Rgb = Rgb::from_u32(0xe6e6fa);
+
+/// CSS `lavenderblush` (`#fff0f5`)
+pub const LAVENDER_BLUSH: Rgb = Rgb::from_u32(0xfff0f5);
+
+/// CSS `lawngreen` (`#7cfc00`)
+pub const LAWN_GREEN: Rgb = Rgb::from_u32(0x7cfc00);
+
+/// CSS `lemonchiffon` (`#fffacd`)
+pub const LEMON_CHIFFON: Rgb = Rgb::from_u32(0xfffacd);
+
+/// CSS `lightblue` (`#add8e6`)
+pub const LIGHT_BLUE: Rgb = Rgb::from_u32(0xadd8e6);
+
+/// CSS `lightcoral` (`#f08080`)
+pub const LIGHT_CORAL: Rgb = Rgb::from_u32(0xf08080);
+
+/// CSS `lightcyan` (`#e0ffff`)
+pub const LIGHT_CYAN: Rgb = Rgb::from_u32(0xe0ffff);
+
+/// CSS `lightgoldenrodyellow` (`#fafad2`)
+pub const LIGHT_GOLDENROD_YELLOW: Rgb = Rgb::from_u32(0xfafad2);
+
+/// CSS `lightgray` (`#d3d3d3`)
+pub const LIGHT_GRAY: Rgb = Rgb::from_u32(0xd3d3d3);
+
+/// CSS `lightgreen` (`#90ee90`)
+pub const LIGHT_GREEN: Rgb = Rgb::from_u32(0x90ee90);
+
+/// CSS `lightgrey` (`#d3d3d3`)
+pub const LIGHT_GREY: Rgb = Rgb::from_u32(0xd3d3d3);
+
+/// CSS `lightpink` (`#ffb6c1`)
+pub const LIGHT_PINK: Rgb = Rgb::from_u32(0xffb6c1);
+
+/// CSS `lightsalmon` (`#ffa07a`)
+pub const LIGHT_SALMON: Rgb = Rgb::from_u32(0xffa07a);
+
+/// CSS `lightseagreen` (`#20b2aa`)
+pub const LIGHT_SEA_GREEN: Rgb = Rgb::from_u32(0x20b2aa);
+
+/// CSS `lightskyblue` (`#87cefa`)
+pub const LIGHT_SKY_BLUE: Rgb = Rgb::from_u32(0x87cefa);
+
+/// CSS `lightslategray` (`#778899`)
+pub const LIGHT_SLATE_GRAY: Rgb = Rgb::from_u32(0x778899);
+
+/// CSS `lightslategrey` (`#778899`)
+pub const LIGHT_SLATE_GREY: Rgb = Rgb::from_u32(0x778899);
+
+/// CSS `lightsteelblue` (`#b0c4de`)
+pub const LIGHT_STEEL_BLUE: Rgb = Rgb::from_u32(0xb0c4de);
+
+/// CSS `lightyellow` (`#ffffe0`)
+pub const LIGHT_YELLOW: Rgb = Rgb::from_u32(0xffffe0);
+
+/// CSS `lime` (`#00ff00`)
+pub const LIME: Rgb = Rgb::from_u32(0x00ff00);
+
+/// CSS `limegreen` (`#32cd32`)
+pub const LIME_GREEN: Rgb = Rgb::from_u32(0x32cd32);
+
+/// CSS `linen` (`#faf0e6`)
+pub const LINEN: Rgb = Rgb::from_u32(0xfaf0e6);
+
+/// CSS `magenta` (`#ff00ff`)
+pub const MAGENTA: Rgb = Rgb::from_u32(0xff00ff);
+
+/// CSS `maroon` (`#800000`)
+pub const MAROON: Rgb = Rgb::from_u32(0x800000);
+
+/// CSS `mediumaquamarine` (`#66cdaa`)
+pub const MEDIUM_AQUAMARINE: Rgb = Rgb::from_u32(0x66cdaa);
+
+/// CSS `mediumblue` (`#0000cd`)
+pub const MEDIUM_BLUE: Rgb = Rgb::from_u32(0x0000cd);
+
+/// CSS `mediumorchid` (`#ba55d3`)
+pub const MEDIUM_ORCHID: Rgb = Rgb::from_u32(0xba55d3);
+
+/// CSS `mediumpurple` (`#9370db`)
+pub const MEDIUM_PURPLE: Rgb = Rgb::from_u32(0x9370db);
+
+/// CSS `mediumseagreen` (`#3cb371`)
+pub const MEDIUM_SEA_GREEN: Rgb = Rgb::from_u32(0x3cb371);
+
+/// CSS `mediumslateblue` (`#7b68ee`)
+pub const MEDIUM_SLATE_BLUE: Rgb = Rgb::from_u32(0x7b68ee);
+
+/// CSS `mediumspringgreen` (`#00fa9a`)
+pub const MEDIUM_SPRING_GREEN: Rgb = Rgb::from_u32(0x00fa9a);
+
+/// CSS `mediumturquoise` (`#48d1cc`)
+pub const MEDIUM_TURQUOISE: Rgb = Rgb::from_u32(0x48d1cc);
+
+/// CSS `mediumvioletred` (`#c71585`)
+pub const MEDIUM_VIOLET_RED: Rgb = Rgb::from_u32(0xc71585);
+
+/// CSS `midnightblue` (`#191970`)
+pub const MIDNIGHT_BLUE: Rgb = Rgb::from_u32(0x191970);
+
+/// CSS `mintcream` (`#f5fffa`)
+pub const MINT_CREAM: Rgb = Rgb::from_u32(0xf5fffa);
+
+/// CSS `mistyrose` (`#ffe4e1`)
+pub const MISTY_ROSE: Rgb = Rgb::from_u32(0xffe4e1);
+
+/// CSS `moccasin` (`#ffe4b5`)
+pub const MOCCASIN: Rgb = Rgb::from_u32(0xffe4b5);
+
+/// CSS `navajowhite` (`#ffdead`)
+pub const NAVAJO_WHITE: Rgb = Rgb::from_u32(0xffdead);
+
+/// CSS `navy` (`#000080`)
+pub const NAVY: Rgb = Rgb::from_u32(0x000080);
+
+/// CSS `oldlace` (`#fdf5e6`)
+pub const OLD_LACE: Rgb = Rgb::from_u32(0xfdf5e6);
+
+/// CSS `olive` (`#808000`)
+pub const OLIVE: Rgb = Rgb::from_u32(0x808000);
+
+/// CSS `olivedrab` (`#6b8e23`)
+pub const OLIVE_DRAB: Rgb = Rgb::from_u32(0x6b8e23);
+
+/// CSS `orange` (`#ffa500`)
+pub const ORANGE: Rgb = Rgb::from_u32(0xffa500);
+
+/// CSS `orangered` (`#ff4500`)
+pub const ORANGE_RED: Rgb = Rgb::from_u32(0xff4500);
+
+/// CSS `orchid` (`#da70d6`)
+pub const ORCHID: Rgb = Rgb::from_u32(0xda70d6);
+
+/// CSS `palegoldenrod` (`#eee8aa`)
+pub const PALE_GOLDENROD: Rgb = Rgb::from_u32(0xeee8aa);
+
+/// CSS `palegreen` (`#98fb98`)
+pub const PALE_GREEN: Rgb = Rgb::from_u32(0x98fb98);
+
+/// CSS `paleturquoise` (`#afeeee`)
+pub const PALE_TURQUOISE: Rgb = Rgb::from_u32(0xafeeee);
+
+/// CSS `palevioletred` (`#db7093`)
+pub const PALE_VIOLET_RED: Rgb = Rgb::from_u32(0xdb7093);
+
+/// CSS `papayawhip` (`#ffefd5`)
+pub const PAPAYA_WHIP: Rgb = Rgb::from_u32(0xffefd5);
+
+/// CSS `peachpuff` (`#ffdab9`)
+pub const PEACH_PUFF: Rgb = Rgb::from_u32(0xffdab9);
+
+/// CSS `peru` (`#cd853f`)
+pub const PERU: Rgb = Rgb::from_u32(0xcd853f);
+
+/// CSS `pink` (`#ffc0cb`)
+pub const PINK: Rgb = Rgb::from_u32(0xffc0cb);
+
+/// CSS `plum` (`#dda0dd`)
+pub const PLUM: Rgb = Rgb::from_u32(0xdda0dd);
+
+/// CSS `powderblue` (`#b0e0e6`)
+pub const POWDER_BLUE: Rgb = Rgb::from_u32(0xb0e0e6);
+
+/// CSS `purple` (`#800080`)
+pub const PURPLE: Rgb = Rgb::from_u32(0x800080);
+
+/// CSS `rebeccapurple` (`#663399`)
+pub const REBECCA_PURPLE: Rgb = Rgb::from_u32(0x663399);
+
+/// CSS `red` (`#ff0000`)
+pub const RED: Rgb = Rgb::from_u32(0xff0000);
+
+/// CSS `rosybrown` (`#bc8f8f`)
+pub const ROSY_BROWN: Rgb = Rgb::from_u32(0xbc8f8f);
+
+/// CSS `royalblue` (`#4169e1`)
+pub const ROYAL_BLUE: Rgb = Rgb::from_u32(0x4169e1);
+
+/// CSS `saddlebrown` (`#8b4513`)
+pub const SADDLE_BROWN: Rgb = Rgb::from_u32(0x8b4513);
+
+/// CSS `salmon` (`#fa8072`)
+pub const SALMON: Rgb = Rgb::from_u32(0xfa8072);
+
+/// CSS `sandybrown` (`#f4a460`)
+pub const SANDY_BROWN: Rgb = Rgb::from_u32(0xf4a460);
+
+/// CSS `seagreen` (`#2e8b57`)
+pub const SEA_GREEN: Rgb = Rgb::from_u32(0x2e8b57);
+
+/// CSS `seashell` (`#fff5ee`)
+pub const SEASHELL: Rgb = Rgb::from_u32(0xfff5ee);
+
+/// CSS `sienna` (`#a0522d`)
+pub const SIENNA: Rgb = Rgb::from_u32(0xa0522d);
+
+/// CSS `silver` (`#c0c0c0`)
+pub const SILVER: Rgb = Rgb::from_u32(0xc0c0c0);
+
+/// CSS `skyblue` (`#87ceeb`)
+pub const SKY_BLUE: Rgb = Rgb::from_u32(0x87ceeb);
+
+/// CSS `slateblue` (`#6a5acd`)
+pub const SLATE_BLUE: Rgb = Rgb::from_u32(0x6a5acd);
+
+/// CSS `slategray` (`#708090`)
+pub const SLATE_GRAY: Rgb = Rgb::from_u32(0x708090);
+
+/// CSS `slategrey` (`#708090`)
+pub const SLATE_GREY: Rgb = Rgb::from_u32(0x708090);
+
+/// CSS `snow` (`#fffafa`)
+pub const SNOW: Rgb = Rgb::from_u32(0xfffafa);
+
+/// CSS `springgreen` (`#00ff7f`)
+pub const SPRING_GREEN: Rgb = Rgb::from_u32(0x00ff7f);
+
+/// CSS `steelblue` (`#4682b4`)
+pub const STEEL_BLUE: Rgb = Rgb::from_u32(0x4682b4);
+
+/// CSS `tan` (`#d2b48c`)
+pub const TAN: Rgb = Rgb::from_u32(0xd2b48c);
+
+/// CSS `teal` (`#008080`)
+pub const TEAL: Rgb = Rgb::from_u32(0x008080);
+
+/// CSS `thistle` (`#d8bfd8`)
+pub const THISTLE: Rgb = Rgb::from_u32(0xd8bfd8);
+
+/// CSS `tomato` (`#ff6347`)
+pub const TOMATO: Rgb = Rgb::from_u32(0xff6347);
+
+/// CSS `turquoise` (`#40e0d0`)
+pub const TURQUOISE: Rgb = Rgb::from_u32(0x40e0d0);
+
+/// CSS `violet` (`#ee82ee`)
+pub const VIOLET: Rgb = Rgb::from_u32(0xee82ee);
+
+/// CSS `wheat` (`#f5deb3`)
+pub const WHEAT: Rgb = Rgb::from_u32(0xf5deb3);
+
+/// CSS `white` (`#ffffff`)
+pub const WHITE: Rgb = Rgb::from_u32(0xffffff);
+
+/// CSS `whitesmoke` (`#f5f5f5`)
+pub const WHITE_SMOKE: Rgb = Rgb::from_u32(0xf5f5f5);
+
+/// CSS `yellow` (`#ffff00`)
+pub const YELLOW: Rgb = Rgb::from_u32(0xffff00);
+
+/// CSS `yellowgreen` (`#9acd32`)
+pub const YELLOW_GREEN: Rgb = Rgb::from_u32(0x9acd32);
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_known_values() {
+		assert_eq!(RED, Rgb::new(255, 0, 0));
+		assert_eq!(CYAN, Rgb::new(0, 255, 255));
+		assert_eq!(AQUA, CYAN);
+		assert_eq!(REBECCA_PURPLE, Rgb::new(102, 51, 153));
+		assert_eq!(CORNFLOWER_BLUE, Rgb::new(100, 149, 237));
+		assert_eq!(LIGHT_GOLDENROD_YELLOW, Rgb::new(250, 250, 210));
+		assert_eq!(DARK_SLATE_GRAY, DARK_SLATE_GREY);
+	}
+
+	#[test]
+	fn test_matches_parse() {
+		assert_eq!(Rgb::from_name("rebeccapurple").unwrap(), REBECCA_PURPLE);
+		assert_eq!(Rgb::from_name("navajowhite").unwrap(), NAVAJO_WHITE);
+		assert_eq!(WHITE_SMOKE.nearest_name(), "whitesmoke");
+	}
+
+	#[test]
+	fn test_lookup_table() {
+		let table = &crate::names::COLORS;
+
+		// `nearest_name` relies on alphabetical order to break ties
+		assert!(table.windows(2).all(|pair| pair[0].0 < pair[1].0));
+		for &(name, rgb) in table.iter() {
+			assert_eq!(Rgb::from_name(name), Some(rgb), "{}", name);
+		}
+	}
+}
@@ -0,0 +1,156 @@
+use crate::{named, Rgb};
+
+///
+/// The CSS Color Module Level 4 named colors, in alphabetical order, built
+/// from the constants in [`named`] so that the two cannot drift apart.
+///
+pub(crate) const COLORS: [(&str, Rgb); 148] = [
+	("aliceblue", named::ALICE_BLUE),
+	("antiquewhite", named::ANTIQUE_WHITE),
+	("aqua", named::AQUA),
+	("aquamarine", named::AQUAMARINE),
+	("azure", named::AZURE),
+	("beige", named::BEIGE),
+	("bisque", named::BISQUE),
+	("black", named::BLACK),
+	("blanchedalmond", named::BLANCHED_ALMOND),
+	("blue", named::BLUE),
+	("blueviolet", named::BLUE_VIOLET),
+	("brown", named::BROWN),
+	("burlywood", named::BURLYWOOD),
+	("cadetblue", named::CADET_BLUE),
+	("chartreuse", named::CHARTREUSE),
+	("chocolate", named::CHOCOLATE),
+	("coral", named::CORAL),
+	("cornflowerblue", named::CORNFLOWER_BLUE),
+	("cornsilk", named::CORNSILK),
+	("crimson", named::CRIMSON),
+	("cyan", named::CYAN),
+	("darkblue", named::DARK_BLUE),
+	("darkcyan", named::DARK_CYAN),
+	("darkgoldenrod", named::DARK_GOLDENROD),
+	("darkgray", named::DARK_GRAY),
+	("darkgreen", named::DARK_GREEN),
+	("darkgrey", named::DARK_GREY),
+	("darkkhaki", named::DARK_KHAKI),
+	("darkmagenta", named::DARK_MAGENTA),
+	("darkolivegreen", named::DARK_OLIVE_GREEN),
+	("darkorange", named::DARK_ORANGE),
+	("darkorchid", named::DARK_ORCHID),
+	("darkred", named::DARK_RED),
+	("darksalmon", named::DARK_SALMON),
+	("darkseagreen", named::DARK_SEA_GREEN),
+	("darkslateblue", named::DARK_SLATE_BLUE),
+	("darkslategray", named::DARK_SLATE_GRAY),
+	("darkslategrey", named::DARK_SLATE_GREY),
+	("darkturquoise", named::DARK_TURQUOISE),
+	("darkviolet", named::DARK_VIOLET),
+	("deeppink", named::DEEP_PINK),
+	("deepskyblue", named::DEEP_SKY_BLUE),
+	("dimgray", named::DIM_GRAY),
+	("dimgrey", named::DIM_GREY),
+	("dodgerblue", named::DODGER_BLUE),
+	("firebrick", named::FIREBRICK),
+	("floralwhite", named::FLORAL_WHITE),
+	("forestgreen", named::FOREST_GREEN),
+	("fuchsia", named::FUCHSIA),
+	("gainsboro", named::GAINSBORO),
+	("ghostwhite", named::GHOST_WHITE),
+	("gold", named::GOLD),
+	("goldenrod", named::GOLDENROD),
+	("gray", named::GRAY),
+	("green", named::GREEN),
+	("greenyellow", named::GREEN_YELLOW),
+	("grey", named::GREY),
+	("honeydew", named::HONEYDEW),
+	("hotpink", named::HOT_PINK),
+	("indianred", named::INDIAN_RED),
+	("indigo", named::INDIGO),
+	("ivory", named::IVORY),
+	("khaki", named::KHAKI),
+	("lavender", named::LAVENDER),
+	("lavenderblush", named::LAVENDER_BLUSH),
+	("lawngreen", named::LAWN_GREEN),
+	("lemonchiffon", named::LEMON_CHIFFON),
+	("lightblue", named::LIGHT_BLUE),
+	("lightcoral", named::LIGHT_CORAL),
+	("lightcyan", named::LIGHT_CYAN),
+	("lightgoldenrodyellow", named::LIGHT_GOLDENROD_YELLOW),
+	("lightgray", named::LIGHT_GRAY),
+	("lightgreen", named::LIGHT_GREEN),
+	("lightgrey", named::LIGHT_GREY),
+	("lightpink", named::LIGHT_PINK),
+	("lightsalmon", named::LIGHT_SALMON),
+	("lightseagreen", named::LIGHT_SEA_GREEN),
+	("lightskyblue", named::LIGHT_SKY_BLUE),
+	("lightslategray", named::LIGHT_SLATE_GRAY),
+	("lightslategrey", named::LIGHT_SLATE_GREY),
+	("lightsteelblue", named::LIGHT_STEEL_BLUE),
+	("lightyellow", named::LIGHT_YELLOW),
+	("lime", named::LIME),
+	("limegreen", named::LIME_GREEN),
+	("linen", named::LINEN),
+	("magenta", named::MAGENTA),
+	("maroon", named::MAROON),
+	("mediumaquamarine", named::MEDIUM_AQUAMARINE),
+	("mediumblue", named::MEDIUM_BLUE),
+	("mediumorchid", named::MEDIUM_ORCHID),
+	("mediumpurple", named::MEDIUM_PURPLE),
+	("mediumseagreen", named::MEDIUM_SEA_GREEN),
+	("mediumslateblue", named::MEDIUM_SLATE_BLUE),
+	("mediumspringgreen", named::MEDIUM_SPRING_GREEN),
+	("mediumturquoise", named::MEDIUM_TURQUOISE),
+	("mediumvioletred", named::MEDIUM_VIOLET_RED),
+	("midnightblue", named::MIDNIGHT_BLUE),
+	("mintcream", named::MINT_CREAM),
+	("mistyrose", named::MISTY_ROSE),
+	("moccasin", named::MOCCASIN),
+	("navajowhite", named::NAVAJO_WHITE),
+	("navy", named::NAVY),
+	("oldlace", named::OLD_LACE),
+	("olive", named::OLIVE),
+	("olivedrab", named::OLIVE_DRAB),
+	("orange", named::ORANGE),
+	("orangered", named::ORANGE_RED),
+	("orchid", named::ORCHID),
+	("palegoldenrod", named::PALE_GOLDENROD),
+	("palegreen", named::PALE_GREEN),
+	("paleturquoise", named::PALE_TURQUOISE),
+	("palevioletred", named::PALE_VIOLET_RED),
+	("papayawhip", named::PAPAYA_WHIP),
+	("peachpuff", named::PEACH_PUFF),
+	("peru", named::PERU),
+	("pink", named::PINK),
+	("plum", named::PLUM),
+	("powderblue", named::POWDER_BLUE),
+	("purple", named::PURPLE),
+	("rebeccapurple", named::REBECCA_PURPLE),
+	("red", named::RED),
+	("rosybrown", named::ROSY_BROWN),
+	("royalblue", named::ROYAL_BLUE),
+	("saddlebrown", named::SADDLE_BROWN),
+	("salmon", named::SALMON),
+	("sandybrown", named::SANDY_BROWN),
+	("seagreen", named::SEA_GREEN),
+	("seashell", named::SEASHELL),
+	("sienna", named::SIENNA),
+	("silver", named::SILVER),
+	("skyblue", named::SKY_BLUE),
+	("slateblue", named::SLATE_BLUE),
+	("slategray", named::SLATE_GRAY),
+	("slategrey", named::SLATE_GREY),
+	("snow", named::SNOW),
+	("springgreen", named::SPRING_GREEN),
+	("steelblue", named::STEEL_BLUE),
+	("tan", named::TAN),
+	("teal", named::TEAL),
+	("thistle", named::THISTLE),
+	("tomato", named::TOMATO),
+	("turquoise", named::TURQUOISE),
+	("violet", named::VIOLET),
+	("wheat", named::WHEAT),
+	("white", named::WHITE),
+	("whitesmoke", named::WHITE_SMOKE),
+	("yellow", named::YELLOW),
+	("yellowgreen", named::YELLOW_GREEN),
+];
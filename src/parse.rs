@@ -0,0 +1,310 @@
+use crate::prelude::*;
+use core::str::FromStr;
+
+///
+/// Splits a CSS-style functional notation string such as `rgb(30, 50, 60)`
+/// into its trimmed, comma-separated arguments, returning a
+/// [`Error::ParseError`] if the input isn't of the form `name(...)`.
+///
+pub(crate) fn function_args<'a>(input: &'a str, name: &str) -> Result<Vec<&'a str>, Error> {
+	let args = input
+		.trim()
+		.strip_prefix(name)
+		.and_then(|rest| rest.strip_prefix('('))
+		.and_then(|rest| rest.strip_suffix(')'))
+		.ok_or_else(|| Error::ParseError(format!("expected `{}(...)`, got `{}`", name, input)))?;
+
+	Ok(args.split(',').map(str::trim).collect())
+}
+
+///
+/// Parses an angle argument of a CSS-style functional notation string, which
+/// may be bare or suffixed with `deg` or `°`. Returns a [`Error::ParseError`]
+/// for any other unit, or if it isn't a valid number.
+///
+pub(crate) fn angle<T: FromStr>(arg: &str) -> Result<T, Error> {
+	arg.strip_suffix("deg")
+		.or_else(|| arg.strip_suffix('°'))
+		.unwrap_or(arg)
+		.parse::<T>()
+		.map_err(|_| Error::ParseError(format!("invalid angle `{}`", arg)))
+}
+
+///
+/// Parses a percentage argument of a CSS-style functional notation string,
+/// which may be bare or suffixed with `%`. Returns a [`Error::ParseError`]
+/// for any other unit, or if it isn't a valid number.
+///
+pub(crate) fn percentage<T: FromStr>(arg: &str) -> Result<T, Error> {
+	arg.strip_suffix('%')
+		.unwrap_or(arg)
+		.parse::<T>()
+		.map_err(|_| Error::ParseError(format!("invalid percentage `{}`", arg)))
+}
+
+///
+/// Parses an alpha argument of a CSS-style functional notation string,
+/// which is either a number from `0.0` to `1.0` or a percentage, which is
+/// scaled to that range. Returns a [`Error::ParseError`] if it isn't a
+/// valid number. The range itself is left for the caller to check.
+///
+pub(crate) fn alpha(arg: &str) -> Result<f64, Error> {
+	let invalid = |_| Error::ParseError(format!("invalid alpha `{}`", arg));
+
+	match arg.strip_suffix('%') {
+		Some(percent) => percent
+			.parse::<f64>()
+			.map(|percent| percent / 100.)
+			.map_err(invalid),
+		None => arg.parse::<f64>().map_err(invalid),
+	}
+}
+
+///
+/// Parses the color channels of an `rgb(...)` or `rgba(...)` string. As in
+/// CSS, the channels are either all integers from 0 to 255 or all
+/// percentages from `0%` to `100%`, which are scaled to 0 to 255. Returns a
+/// [`Error::ParseError`] if the two forms are mixed or any channel is
+/// invalid or out of range.
+///
+pub(crate) fn rgb_channels(args: &[&str]) -> Result<Vec<u8>, Error> {
+	let percentages = args.iter().filter(|arg| arg.ends_with('%')).count();
+	if percentages != 0 && percentages != args.len() {
+		return Err(Error::ParseError(String::from(
+			"cannot mix integer and percentage channels",
+		)));
+	}
+
+	args.iter()
+		.map(|channel| {
+			let invalid = || Error::ParseError(format!("invalid channel value `{}`", channel));
+
+			match channel.strip_suffix('%') {
+				Some(percent) => percent
+					.parse::<f64>()
+					.ok()
+					.filter(|percent| (0. ..=100.).contains(percent))
+					.map(|percent| (percent / 100. * 255.).round() as u8)
+					.ok_or_else(invalid),
+				None => channel.parse::<u8>().map_err(|_| invalid()),
+			}
+		})
+		.collect()
+}
+
+///
+/// Parses the arguments of a CSS-style functional notation string (see
+/// [`function_args`]) as percentages (see [`percentage`]). Returns a
+/// [`Error::ParseError`] unless exactly `count` arguments are present and all
+/// of them are valid.
+///
+fn percentage_args<T: FromStr>(input: &str, name: &str, count: usize) -> Result<Vec<T>, Error> {
+	let args = function_args(input, name)?
+		.into_iter()
+		.map(percentage)
+		.collect::<Result<Vec<_>, _>>()?;
+
+	if args.len() != count {
+		return Err(Error::ParseError(format!(
+			"expected {} values, got {}",
+			count,
+			args.len()
+		)));
+	}
+
+	Ok(args)
+}
+
+///
+/// Parses a color in any of the syntaxes understood by this crate, detecting
+/// the format from the input, and normalizes it to [`Rgb`]. The supported
+/// syntaxes are:
+///
+/// * hex, e.g. `#0ff` or `#00ffff`
+/// * `rgb(0, 255, 255)` or `rgb(0%, 100%, 100%)`
+/// * `hsl(180deg, 100%, 50%)`, with the `deg` (or `°`) and `%` units being
+///   optional
+/// * `cmyk(100%, 0%, 0%, 0%)`, with the `%` units being optional
+/// * CSS color names, e.g. `cyan`
+///
+/// Returns an [`Error`] if the input matches none of them, or if it is
+/// malformed or out of range for the syntax it matches.
+///
+/// # Arguments
+///
+/// * `input` - the string to parse
+///
+/// # Examples
+///
+/// ```
+/// use color_conv::{parse_any, Rgb};
+///
+/// let cyan = Rgb::new(0, 255, 255);
+///
+/// assert_eq!(parse_any("#0ff")?, cyan);
+/// assert_eq!(parse_any("hsl(180°, 100%, 50%)")?, cyan);
+/// assert_eq!(parse_any("cyan")?, cyan);
+/// # Ok::<(), color_conv::Error>(())
+/// ```
+///
+pub fn parse_any(input: &str) -> Result<Rgb, Error> {
+	let input = input.trim();
+
+	if input.starts_with('#') {
+		Rgb::from_hex_str(input)
+	} else if input.starts_with("rgb(") {
+		input.parse()
+	} else if input.starts_with("hsl(") {
+		Ok(input.parse::<Hsl>()?.to_rgb())
+	} else if input.starts_with("cmyk(") {
+		let args = percentage_args::<u8>(input, "cmyk", 4)?;
+		Ok(Cmyk::new(args[0], args[1], args[2], args[3])?.to_rgb())
+	} else {
+		Rgb::from_name(input)
+			.ok_or_else(|| Error::ParseError(format!("unrecognized color `{}`", input)))
+	}
+}
+
+///
+/// Parses a hex color string with an optional leading hashtag (`#`) into
+/// `channels` bytes. Both the full form (two digits per channel) and the
+/// shorthand form (one digit per channel, e.g. `#abc` for `#aabbcc`) are
+/// accepted. Returns an [`Error::InvalidHex`] otherwise.
+///
+pub(crate) fn hex_channels(input: &str, channels: usize) -> Result<Vec<u8>, Error> {
+	let digits = input.strip_prefix('#').unwrap_or(input);
+
+	if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+		return Err(Error::InvalidHex);
+	}
+
+	let width = match digits.len() {
+		len if len == channels => 1,
+		len if len == channels * 2 => 2,
+		_ => return Err(Error::InvalidHex),
+	};
+
+	Ok((0..channels)
+		.map(|i| {
+			let value = u8::from_str_radix(&digits[i * width..(i + 1) * width], 16).unwrap();
+			if width == 1 {
+				value * 0x11
+			} else {
+				value
+			}
+		})
+		.collect())
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_parse_any() {
+		let cyan = Rgb::new(0, 255, 255);
+
+		assert_eq!(parse_any("#0ff").unwrap(), cyan);
+		assert_eq!(parse_any("#00ffff").unwrap(), cyan);
+		assert_eq!(parse_any("rgb(0, 255, 255)").unwrap(), cyan);
+		assert_eq!(parse_any("rgb(0%, 100%, 100%)").unwrap(), cyan);
+		assert_eq!(parse_any("hsl(180°, 100%, 50%)").unwrap(), cyan);
+		assert_eq!(parse_any("hsl(180, 100, 50)").unwrap(), cyan);
+		assert_eq!(parse_any("cmyk(100%, 0%, 0%, 0%)").unwrap(), cyan);
+		assert_eq!(parse_any("  Cyan ").unwrap(), cyan);
+	}
+
+	#[test]
+	fn test_parse_any_invalid() {
+		assert!(matches!(
+			parse_any("not a color"),
+			Err(Error::ParseError(_))
+		));
+		assert!(matches!(parse_any("#ggg"), Err(Error::InvalidHex)));
+		assert!(matches!(
+			parse_any("hsl(180, 300%, 50%)"),
+			Err(Error::PercentageOverflow)
+		));
+		assert!(matches!(
+			parse_any("cmyk(100, 0, 0)"),
+			Err(Error::ParseError(_))
+		));
+	}
+
+	#[test]
+	fn test_parse_any_units() {
+		assert!(matches!(
+			parse_any("rgb(100%%, 0%, 0%)"),
+			Err(Error::ParseError(_))
+		));
+		assert!(matches!(
+			parse_any("rgb(100°, 0, 0)"),
+			Err(Error::ParseError(_))
+		));
+		assert!(matches!(
+			parse_any("hsl(180, 100°, 50%)"),
+			Err(Error::ParseError(_))
+		));
+		assert!(matches!(
+			parse_any("cmyk(50°, 0%, 0%, 0%)"),
+			Err(Error::ParseError(_))
+		));
+		assert!(matches!(
+			parse_any("cmyk(100%%, 0%, 0%, 0%)"),
+			Err(Error::ParseError(_))
+		));
+	}
+
+	#[test]
+	fn test_angle() {
+		assert_eq!(angle::<u16>("180").unwrap(), 180);
+		assert_eq!(angle::<u16>("180deg").unwrap(), 180);
+		assert_eq!(angle::<u16>("180°").unwrap(), 180);
+		assert!(matches!(angle::<u16>("180%"), Err(Error::ParseError(_))));
+		assert!(matches!(angle::<u16>("0.5turn"), Err(Error::ParseError(_))));
+	}
+
+	#[test]
+	fn test_percentage() {
+		assert_eq!(percentage::<u8>("50").unwrap(), 50);
+		assert_eq!(percentage::<u8>("50%").unwrap(), 50);
+		assert!(matches!(
+			percentage::<u8>("50deg"),
+			Err(Error::ParseError(_))
+		));
+		assert!(matches!(
+			percentage::<u8>("50%%"),
+			Err(Error::ParseError(_))
+		));
+	}
+
+	#[test]
+	fn test_rgb_channels() {
+		assert_eq!(rgb_channels(&["30", "50", "60"]).unwrap(), vec![30, 50, 60]);
+		assert_eq!(
+			rgb_channels(&["100%", "0%", "50%"]).unwrap(),
+			vec![255, 0, 128]
+		);
+		assert!(matches!(
+			rgb_channels(&["100%", "0", "50%"]),
+			Err(Error::ParseError(_))
+		));
+		assert!(matches!(
+			rgb_channels(&["101%", "0%", "0%"]),
+			Err(Error::ParseError(_))
+		));
+		assert!(matches!(
+			rgb_channels(&["256", "0", "0"]),
+			Err(Error::ParseError(_))
+		));
+	}
+
+	#[test]
+	fn test_alpha() {
+		assert_eq!(alpha("0.5").unwrap(), 0.5);
+		assert_eq!(alpha("50%").unwrap(), 0.5);
+		assert_eq!(alpha("1").unwrap(), 1.);
+		assert!(matches!(alpha("0.5deg"), Err(Error::ParseError(_))));
+		assert!(matches!(alpha("half"), Err(Error::ParseError(_))));
+	}
+}
@@ -0,0 +1,27 @@
+//!
+//! Re-exports the [`Color`] and [`Invert`] traits, every color type, and the
+//! crate-wide [`Error`] type so they can all be imported at once.
+//!
+//! ```
+//! use color_conv::prelude::*;
+//!
+//! let cyan = Cmyk::new(100, 0, 0, 0)?;
+//! assert_eq!(cyan.to_rgb(), Rgb::new(0, 255, 255));
+//! assert_eq!(cyan.to_hsl(), Hsl::new(180, 100, 50)?);
+//! # Ok::<(), Error>(())
+//! ```
+//!
+
+pub use crate::{
+	Cmy, Cmyk, Color, Error, Float, Hsl, Hsla, Hsv, Hwb, Invert, Lab, Rgb, Rgba, Xyz, YCbCr,
+};
+
+// The test harness links `std`, whose inherent float methods take precedence
+#[cfg(not(any(feature = "std", test)))]
+pub(crate) use crate::float::FloatExt;
+pub(crate) use alloc::{
+	format,
+	string::{String, ToString},
+	vec,
+	vec::Vec,
+};
@@ -1,10 +1,24 @@
-use crate::{Cmyk, Color, Hsl};
-use std::fmt;
+use crate::{
+	names, parse,
+	prelude::*,
+	ycbcr::{YCbCrMatrix, YCbCrRange},
+};
+use core::{
+	convert::TryFrom,
+	fmt,
+	ops::{Add, Mul, Sub},
+	str::FromStr,
+};
 
 ///
 /// A representation of the RGB (red, green, blue) color format.
 ///
-#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+/// Colors are ordered lexicographically by red, then green, then blue. This
+/// gives a stable, deterministic order, but not a perceptual one; use
+/// [`Rgb::sort_key_by_luminance`] to sort by perceived brightness instead.
+///
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rgb {
 	/// Red value
 	pub red: u8,
@@ -14,6 +28,70 @@ pub struct Rgb {
 	pub blue: u8,
 }
 
+///
+/// The algorithm used by [`Rgb::to_grayscale_with`] to collapse a color into
+/// a single gray value.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum GrayscaleMethod {
+	/// The mean of the red, green, and blue channels
+	Average,
+	/// Perceptual luminance using the Rec. 709 channel weights
+	Luminance,
+	/// The midpoint of the largest and smallest channels, as in HSL
+	Lightness,
+}
+
+///
+/// A type of color vision deficiency simulated by [`Rgb::simulate_cvd`].
+/// Each is a dichromacy, in which one of the three kinds of cone cells is
+/// missing.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum CvdType {
+	/// Missing long-wavelength (red) cones
+	Protanopia,
+	/// Missing medium-wavelength (green) cones
+	Deuteranopia,
+	/// Missing short-wavelength (blue) cones
+	Tritanopia,
+}
+
+///
+/// One of the three channels of an [`Rgb`] color, as returned by
+/// [`Rgb::dominant_channel`].
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum Channel {
+	/// The red channel
+	Red,
+	/// The green channel
+	Green,
+	/// The blue channel
+	Blue,
+}
+
+///
+/// Formatting options for [`Rgb::to_hex_string_with`]. The default matches
+/// [`Color::to_hex_string`]: lowercase digits with a leading `#`.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub struct HexOptions {
+	/// Whether to use uppercase hex digits
+	pub uppercase: bool,
+	/// Whether to prefix the string with a hashtag (`#`)
+	pub hash: bool,
+}
+
+impl Default for HexOptions {
+	fn default() -> Self {
+		Self {
+			uppercase: false,
+			hash: true,
+		}
+	}
+}
+
 impl Rgb {
 	///
 	/// Returns a new Rgb object given red, green, and blue values.
@@ -37,111 +115,3415 @@ impl Rgb {
 	/// * `red`, `green`, and `blue` are all 8-bit integers with a maximum value
 	///   of 255.
 	///
-	pub fn new(red: u8, green: u8, blue: u8) -> Self {
+	pub const fn new(red: u8, green: u8, blue: u8) -> Self {
 		Self { red, green, blue }
 	}
 
-	fn _to_cmyk(self) -> (u8, u8, u8, u8) {
-		let r_prime = self.red as f64 / 255.;
-		let g_prime = self.green as f64 / 255.;
-		let b_prime = self.blue as f64 / 255.;
+	///
+	/// Returns a Result containing a new Rgb object given red, green, and
+	/// blue values as `i32`s, such as those produced by parsing. Will return
+	/// an [`Error::ChannelOverflow`] if any value is outside of the range 0
+	/// to 255 rather than truncating it.
+	///
+	/// # Arguments
+	///
+	/// * `red` - the red value of the color
+	/// * `green` - the green value of the color
+	/// * `blue` - the blue value of the color
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let cyan = Rgb::try_new(0, 255, 255)?;
+	/// assert_eq!(cyan, Rgb::new(0, 255, 255));
+	///
+	/// assert!(Rgb::try_new(0, 256, 255).is_err());
+	/// # Ok::<(), color_conv::Error>(())
+	/// ```
+	///
+	pub fn try_new(red: i32, green: i32, blue: i32) -> Result<Self, Error> {
+		let channel = |value: i32| u8::try_from(value).map_err(|_| Error::ChannelOverflow);
+		Ok(Self::new(channel(red)?, channel(green)?, channel(blue)?))
+	}
 
-		let key = 1.
-			- [r_prime, g_prime, b_prime]
-				.iter()
-				.cloned()
-				.fold(f64::NAN, f64::max);
+	///
+	/// Returns a copy of the color with the red channel replaced.
+	///
+	/// # Arguments
+	///
+	/// * `red` - the new red value
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let rgb = Rgb::new(30, 50, 60).with_red(255);
+	/// assert_eq!(rgb, Rgb::new(255, 50, 60));
+	/// ```
+	///
+	pub const fn with_red(self, red: u8) -> Rgb {
+		Rgb::new(red, self.green, self.blue)
+	}
 
-		let apply = |v: f64| (((1. - v - key) / (1. - key)) * 100.).round();
-		let cyan = apply(r_prime);
-		let magenta = apply(g_prime);
-		let yellow = apply(b_prime);
+	///
+	/// Returns a copy of the color with the green channel replaced.
+	///
+	/// # Arguments
+	///
+	/// * `green` - the new green value
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let rgb = Rgb::new(30, 50, 60).with_green(255);
+	/// assert_eq!(rgb, Rgb::new(30, 255, 60));
+	/// ```
+	///
+	pub const fn with_green(self, green: u8) -> Rgb {
+		Rgb::new(self.red, green, self.blue)
+	}
 
-		(cyan as u8, magenta as u8, yellow as u8, (key * 100.) as u8)
+	///
+	/// Returns a copy of the color with the blue channel replaced.
+	///
+	/// # Arguments
+	///
+	/// * `blue` - the new blue value
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let rgb = Rgb::new(30, 50, 60).with_blue(255);
+	/// assert_eq!(rgb, Rgb::new(30, 50, 255));
+	/// ```
+	///
+	pub const fn with_blue(self, blue: u8) -> Rgb {
+		Rgb::new(self.red, self.green, blue)
 	}
-}
 
-impl fmt::Display for Rgb {
-	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		write!(f, "rgb({}, {}, {})", self.red, self.green, self.blue)
+	///
+	/// Returns a Result containing a new Rgb object parsed from a hex color
+	/// string. Accepts the `#rrggbb` and shorthand `#rgb` forms, with or
+	/// without the leading hashtag (`#`). Will return an [`Error::InvalidHex`]
+	/// if the string contains non-hexadecimal digits or has the wrong length.
+	///
+	/// # Arguments
+	///
+	/// * `hex` - the hex string to parse
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let cyan = Rgb::from_hex_str("#00ffff")?;
+	/// assert_eq!(cyan, Rgb::new(0, 255, 255));
+	///
+	/// let short_cyan = Rgb::from_hex_str("0ff")?;
+	/// assert_eq!(short_cyan, cyan);
+	/// # Ok::<(), color_conv::Error>(())
+	/// ```
+	///
+	pub fn from_hex_str(hex: &str) -> Result<Self, Error> {
+		let channels = parse::hex_channels(hex, 3)?;
+		Ok(Self::new(channels[0], channels[1], channels[2]))
 	}
-}
 
-impl Color for Rgb {
-	fn to_rgb(self) -> Rgb {
-		self
+	///
+	/// Returns a new Rgb object unpacked from a `u32` laid out as
+	/// `0x00RRGGBB`. The high byte is ignored.
+	///
+	/// # Arguments
+	///
+	/// * `packed` - the packed color value
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let rgb = Rgb::from_u32(0x001e323c);
+	/// assert_eq!(rgb, Rgb::new(0x1e, 0x32, 0x3c));
+	/// ```
+	///
+	pub const fn from_u32(packed: u32) -> Self {
+		Self::new((packed >> 16) as u8, (packed >> 8) as u8, packed as u8)
 	}
 
-	fn to_hex_string(self) -> String {
-		format!("#{:0>2x}{:0>2x}{:0>2x}", self.red, self.green, self.blue)
+	///
+	/// Packs the color into a `u32` laid out as `0x00RRGGBB`. The high byte is
+	/// always zero.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let packed = Rgb::new(0x1e, 0x32, 0x3c).to_u32();
+	/// assert_eq!(packed, 0x001e323c);
+	/// ```
+	///
+	pub fn to_u32(self) -> u32 {
+		(self.red as u32) << 16 | (self.green as u32) << 8 | self.blue as u32
 	}
 
-	fn to_cmyk(self) -> Cmyk {
-		let (c, m, y, k) = self._to_cmyk();
-		Cmyk::new_unchecked(c, m, y, k)
+	///
+	/// Creates a new Rgb object from bytes ordered R, G, B, as found in
+	/// tightly packed image buffers.
+	///
+	/// # Arguments
+	///
+	/// * `bytes` - the red, green, and blue channels, in that order
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let color = Rgb::from_bytes([30, 50, 60]);
+	/// assert_eq!(color, Rgb::new(30, 50, 60));
+	/// ```
+	///
+	pub fn from_bytes(bytes: [u8; 3]) -> Self {
+		let [red, green, blue] = bytes;
+		Self::new(red, green, blue)
 	}
 
-	fn to_hsl(self) -> Hsl {
-		let Self { red, green, blue } = self;
+	///
+	/// Returns the channels of the color as bytes ordered R, G, B. See
+	/// [`Rgb::from_bytes`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let bytes = Rgb::new(30, 50, 60).to_bytes();
+	/// assert_eq!(bytes, [30, 50, 60]);
+	/// ```
+	///
+	pub fn to_bytes(self) -> [u8; 3] {
+		[self.red, self.green, self.blue]
+	}
 
-		let r_prime = red as f64 / 255.;
-		let g_prime = green as f64 / 255.;
-		let b_prime = blue as f64 / 255.;
+	///
+	/// Returns the red, green, and blue as a tuple, in that order.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let rgb = Rgb::new(30, 50, 60);
+	/// assert_eq!(rgb.to_tuple(), (30, 50, 60));
+	/// ```
+	///
+	pub const fn to_tuple(self) -> (u8, u8, u8) {
+		(self.red, self.green, self.blue)
+	}
 
-		let c_max = [red, green, blue].iter().max().cloned().unwrap() as f64 / 255.;
-		let c_min = [red, green, blue].iter().min().cloned().unwrap() as f64 / 255.;
+	///
+	/// Creates a new Rgb object from channels normalized to the range `0.0`
+	/// to `1.0`, as used by many graphics pipelines. Values outside that
+	/// range are clamped before being scaled to 0-255 and rounded.
+	///
+	/// # Arguments
+	///
+	/// * `red` - the normalized red value
+	/// * `green` - the normalized green value
+	/// * `blue` - the normalized blue value
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// assert_eq!(Rgb::from_f64(0., 0.5, 1.), Rgb::new(0, 128, 255));
+	/// assert_eq!(Rgb::from_f64(-1., 2., 1.), Rgb::new(0, 255, 255));
+	/// ```
+	///
+	pub fn from_f64(red: f64, green: f64, blue: f64) -> Self {
+		let apply = |channel: f64| (channel.clamp(0., 1.) * 255.).round() as u8;
+		Self::new(apply(red), apply(green), apply(blue))
+	}
 
-		let delta = c_max - c_min;
+	///
+	/// Returns the channels of the color normalized to the range `0.0` to
+	/// `1.0`. See [`Rgb::from_f64`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// assert_eq!(Rgb::new(0, 255, 255).to_f64(), (0., 1., 1.));
+	/// ```
+	///
+	pub fn to_f64(self) -> (f64, f64, f64) {
+		(
+			self.red as f64 / 255.,
+			self.green as f64 / 255.,
+			self.blue as f64 / 255.,
+		)
+	}
 
-		let hue = if (delta - 0.) < f64::EPSILON {
-			0
-		} else {
-			match c_max {
-				x if x == r_prime => 60. * (((g_prime - b_prime) / delta) % 6.),
-				x if x == g_prime => 60. * (((b_prime - r_prime) / delta) + 2.),
-				x if x == b_prime => 60. * (((r_prime - g_prime) / delta) + 4.),
-				_ => panic!("Invalid hue calculation!"),
-			}
-			.round() as u16
-		};
+	///
+	/// Converts to a [`String`] containing the uppercase hex code of the
+	/// color prefixed with a hashtag (`#`).
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let cyan = Rgb::new(0, 255, 255);
+	/// assert_eq!(cyan.to_hex_string_upper(), String::from("#00FFFF"));
+	/// ```
+	///
+	pub fn to_hex_string_upper(self) -> String {
+		self.to_hex_string_with(HexOptions {
+			uppercase: true,
+			..HexOptions::default()
+		})
+	}
 
-		let lightness = (c_max + c_min) / 2.;
+	///
+	/// Converts to a [`String`] containing the hex code of the color,
+	/// formatted according to `opts`.
+	///
+	/// # Arguments
+	///
+	/// * `opts` - controls the case of the digits and whether the `#` prefix
+	///   is emitted
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::{HexOptions, Rgb};
+	///
+	/// let cyan = Rgb::new(0, 255, 255);
+	/// let opts = HexOptions {
+	///     uppercase: true,
+	///     hash: false,
+	/// };
+	///
+	/// assert_eq!(cyan.to_hex_string_with(opts), String::from("00FFFF"));
+	/// ```
+	///
+	pub fn to_hex_string_with(self, opts: HexOptions) -> String {
+		let Self { red, green, blue } = self;
+		let prefix = if opts.hash { "#" } else { "" };
 
-		let saturation = if (delta - 0.) < f64::EPSILON {
-			0
+		if opts.uppercase {
+			format!("{}{:0>2X}{:0>2X}{:0>2X}", prefix, red, green, blue)
 		} else {
-			(delta / (1. - ((2. * lightness) - 1.)) * 100.).round() as u8
-		};
-
-		Hsl::new_unchecked(hue, saturation, (lightness * 100.).round() as u8)
+			format!("{}{:0>2x}{:0>2x}{:0>2x}", prefix, red, green, blue)
+		}
 	}
-}
 
-#[cfg(test)]
-mod test {
-	use super::*;
+	///
+	/// Converts to a [`String`] containing the three-digit shorthand hex
+	/// code of the color, such as `#0ff`, if it can be written without loss.
+	/// Returns [`None`] when any channel's two hex digits differ.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// assert_eq!(Rgb::new(0, 255, 255).to_hex_string_short(), Some(String::from("#0ff")));
+	/// assert_eq!(Rgb::new(30, 50, 60).to_hex_string_short(), None);
+	/// ```
+	///
+	pub fn to_hex_string_short(self) -> Option<String> {
+		let Self { red, green, blue } = self;
 
-	#[test]
-	fn test_to_string() {
-		let rgb = Rgb::new(30, 50, 60);
-		assert_eq!(rgb.to_string(), String::from("rgb(30, 50, 60)"));
+		if [red, green, blue]
+			.iter()
+			.all(|channel| channel >> 4 == channel & 0xf)
+		{
+			Some(format!("#{:x}{:x}{:x}", red & 0xf, green & 0xf, blue & 0xf))
+		} else {
+			None
+		}
 	}
 
-	#[test]
-	fn test_to_hex_string() {
-		let hex = Rgb::new(30, 50, 60).to_hex_string();
-		assert_eq!(hex, String::from("#1e323c"));
-	}
+	///
+	/// Returns a breakdown of each channel in both decimal and hex, for
+	/// logging and debugging. This is more useful for color work than the
+	/// derived [`Debug`] output.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// assert_eq!(
+	///     Rgb::new(30, 50, 60).describe(),
+	///     String::from("Rgb { r: 30 (0x1e), g: 50 (0x32), b: 60 (0x3c) }")
+	/// );
+	/// ```
+	///
+	pub fn describe(self) -> String {
+		let Self { red, green, blue } = self;
 
-	#[test]
-	fn test_to_cmyk() {
-		let rgb = Rgb::new(30, 50, 60).to_cmyk();
-		assert_eq!(rgb, Cmyk::new_unchecked(50, 17, 0, 76));
+		format!(
+			"Rgb {{ r: {} ({:#04x}), g: {} ({:#04x}), b: {} ({:#04x}) }}",
+			red, red, green, green, blue, blue
+		)
+	}
+
+	///
+	/// Returns the color with the given CSS named color, such as
+	/// `rebeccapurple`, or [`None`] if no such name exists. Matching is
+	/// case-insensitive.
+	///
+	/// # Arguments
+	///
+	/// * `name` - the CSS name of the color
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let purple = Rgb::from_name("RebeccaPurple");
+	/// assert_eq!(purple, Some(Rgb::new(102, 51, 153)));
+	/// ```
+	///
+	pub fn from_name(name: &str) -> Option<Self> {
+		names::COLORS
+			.iter()
+			.find(|(candidate, _)| candidate.eq_ignore_ascii_case(name))
+			.map(|&(_, rgb)| rgb)
+	}
+
+	///
+	/// Returns the name of the CSS named color closest to this color by
+	/// Euclidean distance in RGB space. When several names share a color,
+	/// such as `gray` and `grey`, the alphabetically first one is returned.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let name = Rgb::new(250, 5, 5).nearest_name();
+	/// assert_eq!(name, "red");
+	/// ```
+	///
+	pub fn nearest_name(self) -> &'static str {
+		names::COLORS
+			.iter()
+			.min_by_key(|&&(_, rgb)| self._distance(rgb))
+			.map(|(name, _)| *name)
+			.unwrap()
+	}
+
+	///
+	/// Returns the entry of `palette` closest to the color by RGB distance
+	/// (see [`Color::distance`](crate::Color::distance)). Ties go to the
+	/// earliest entry. If the palette is empty, the color itself is returned.
+	///
+	/// # Arguments
+	///
+	/// * `palette` - the colors to choose from
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let palette = [Rgb::new(0, 0, 0), Rgb::new(255, 255, 255)];
+	/// assert_eq!(Rgb::new(200, 180, 190).nearest_in(&palette), Rgb::new(255, 255, 255));
+	/// assert_eq!(Rgb::new(200, 180, 190).nearest_in(&[]), Rgb::new(200, 180, 190));
+	/// ```
+	///
+	pub fn nearest_in(self, palette: &[Rgb]) -> Rgb {
+		palette
+			.iter()
+			.copied()
+			.min_by_key(|candidate| self._distance(*candidate))
+			.unwrap_or(self)
+	}
+
+	///
+	/// Returns the most frequent color in `pixels`, or [`None`] if it is
+	/// empty. Ties are broken in favor of the smallest color by the ordering
+	/// of [`Rgb`].
+	///
+	/// # Arguments
+	///
+	/// * `pixels` - the colors to count
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let red = Rgb::new(255, 0, 0);
+	/// let blue = Rgb::new(0, 0, 255);
+	///
+	/// assert_eq!(Rgb::most_common(&[red, blue, red]), Some(red));
+	/// assert_eq!(Rgb::most_common(&[]), None);
+	/// ```
+	///
+	pub fn most_common(pixels: &[Rgb]) -> Option<Rgb> {
+		let mut sorted = pixels.to_vec();
+		sorted.sort_unstable();
+
+		let mut best = None;
+		let mut best_count = 0;
+		let mut start = 0;
+		while start < sorted.len() {
+			let color = sorted[start];
+			let count = sorted[start..]
+				.iter()
+				.take_while(|&&rgb| rgb == color)
+				.count();
+
+			if count > best_count {
+				best = Some(color);
+				best_count = count;
+			}
+			start += count;
+		}
+
+		best
+	}
+
+	///
+	/// Extracts a palette of up to `k` representative colors from `pixels`
+	/// using k-means clustering in RGB space, ordered from the largest
+	/// cluster to the smallest. The clusters are seeded deterministically
+	/// with distinct colors spread evenly by luminance, so the same input
+	/// always gives the same palette. Fewer than `k` colors are returned if
+	/// `pixels` contains fewer distinct colors, or if some clusters end up
+	/// with no pixels.
+	///
+	/// # Arguments
+	///
+	/// * `pixels` - the colors to cluster
+	/// * `k` - the number of colors to extract
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let pixels = [
+	///     Rgb::new(250, 10, 10),
+	///     Rgb::new(240, 0, 20),
+	///     Rgb::new(245, 5, 15),
+	///     Rgb::new(10, 10, 250),
+	/// ];
+	///
+	/// assert_eq!(
+	///     Rgb::dominant_colors(&pixels, 2),
+	///     vec![Rgb::new(245, 5, 15), Rgb::new(10, 10, 250)]
+	/// );
+	/// ```
+	///
+	pub fn dominant_colors(pixels: &[Rgb], k: usize) -> Vec<Rgb> {
+		const MAX_ITERATIONS: usize = 32;
+
+		let mut distinct = pixels.to_vec();
+		distinct.sort_unstable();
+		distinct.dedup();
+		distinct.sort_by_key(|rgb| rgb.sort_key_by_luminance());
+
+		let k = k.min(distinct.len());
+		if k == 0 {
+			return Vec::new();
+		}
+
+		let mut centroids: Vec<Rgb> = (0..k)
+			.map(|cluster| distinct[cluster * (distinct.len() - 1) / (k - 1).max(1)])
+			.collect();
+
+		// Sums the channels of the pixels closest to each centroid, and counts
+		// them
+		let assign = |centroids: &[Rgb]| {
+			let mut sums = vec![[0u64; 3]; k];
+			let mut sizes = vec![0usize; k];
+
+			for pixel in pixels {
+				let cluster = (0..k)
+					.min_by_key(|&cluster| pixel._distance(centroids[cluster]))
+					.unwrap();
+
+				sizes[cluster] += 1;
+				for (sum, channel) in sums[cluster].iter_mut().zip(pixel.to_bytes().iter()) {
+					*sum += *channel as u64;
+				}
+			}
+
+			(sums, sizes)
+		};
+
+		let (mut sums, mut sizes) = assign(&centroids);
+
+		for _ in 0..MAX_ITERATIONS {
+			let updated: Vec<Rgb> = centroids
+				.iter()
+				.zip(sums.iter().zip(sizes.iter()))
+				.map(|(&centroid, (sum, &size))| {
+					if size == 0 {
+						return centroid;
+					}
+
+					let size = size as u64;
+					let apply = |sum: u64| ((sum + size / 2) / size) as u8;
+					Rgb::new(apply(sum[0]), apply(sum[1]), apply(sum[2]))
+				})
+				.collect();
+
+			if updated == centroids {
+				break;
+			}
+
+			centroids = updated;
+			let (next_sums, next_sizes) = assign(&centroids);
+			sums = next_sums;
+			sizes = next_sizes;
+		}
+
+		// Clusters that no pixel is closest to would only repeat their seed
+		let mut clusters: Vec<(Rgb, usize)> = centroids
+			.into_iter()
+			.zip(sizes)
+			.filter(|&(_, size)| size != 0)
+			.collect();
+		clusters.sort_by_key(|&(_, size)| core::cmp::Reverse(size));
+		clusters.into_iter().map(|(color, _)| color).collect()
+	}
+
+	///
+	/// Snaps each channel to the nearest multiple of 51, giving the closest
+	/// color in the 216-color web-safe palette.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let snapped = Rgb::new(200, 200, 200).to_web_safe();
+	/// assert_eq!(snapped, Rgb::new(204, 204, 204));
+	/// ```
+	///
+	pub fn to_web_safe(self) -> Rgb {
+		let apply = |channel: u8| (channel as u16 + 25) / 51 * 51;
+		Rgb::new(
+			apply(self.red) as u8,
+			apply(self.green) as u8,
+			apply(self.blue) as u8,
+		)
+	}
+
+	///
+	/// Reduces each channel to `bits_per_channel` bits, snapping it to the
+	/// nearest of `2^bits` evenly spaced levels, and rescales it back to
+	/// 0-255. A depth of 8 leaves the color unchanged; depths of 0 are
+	/// treated as 1 and depths above 8 as 8.
+	///
+	/// # Arguments
+	///
+	/// * `bits_per_channel` - the bit depth to reduce each channel to
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// assert_eq!(Rgb::new(30, 50, 200).quantize(1), Rgb::new(0, 0, 255));
+	/// assert_eq!(Rgb::new(30, 50, 200).quantize(2), Rgb::new(0, 85, 170));
+	/// ```
+	///
+	pub fn quantize(self, bits_per_channel: u8) -> Rgb {
+		let levels = (1u32 << bits_per_channel.clamp(1, 8)) - 1;
+		let apply = |channel: u8| {
+			let level = (channel as u32 * levels + 127) / 255;
+			((level * 255 + levels / 2) / levels) as u8
+		};
+
+		Rgb::new(apply(self.red), apply(self.green), apply(self.blue))
+	}
+
+	///
+	/// Returns whether the color is part of the 216-color web-safe palette,
+	/// meaning that every channel is a multiple of 51.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// assert!(Rgb::new(0, 102, 255).is_web_safe());
+	/// assert!(!Rgb::new(0, 100, 255).is_web_safe());
+	/// ```
+	///
+	pub fn is_web_safe(self) -> bool {
+		[self.red, self.green, self.blue]
+			.iter()
+			.all(|channel| channel % 51 == 0)
+	}
+
+	///
+	/// Converts to [`YCbCr`] using the BT.601 matrix at full range, as in
+	/// JPEG. See [`Rgb::to_ycbcr_with`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::{Rgb, YCbCr};
+	///
+	/// let ycbcr = Rgb::new(255, 0, 0).to_ycbcr();
+	/// assert_eq!(ycbcr, YCbCr::new(76, 85, 255));
+	/// ```
+	///
+	pub fn to_ycbcr(self) -> YCbCr {
+		self.to_ycbcr_with(YCbCrMatrix::Bt601, YCbCrRange::Full)
+	}
+
+	///
+	/// Converts to [`YCbCr`] using the given matrix and range.
+	///
+	/// # Arguments
+	///
+	/// * `matrix` - the matrix used to derive luma and chroma
+	/// * `range` - the range of code values to encode into
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::{Rgb, YCbCr, YCbCrMatrix, YCbCrRange};
+	///
+	/// let ycbcr = Rgb::new(255, 0, 0).to_ycbcr_with(YCbCrMatrix::Bt709, YCbCrRange::Studio);
+	/// assert_eq!(ycbcr, YCbCr::new(63, 102, 240));
+	/// ```
+	///
+	pub fn to_ycbcr_with(self, matrix: YCbCrMatrix, range: YCbCrRange) -> YCbCr {
+		let (kr, kb) = matrix.coefficients();
+		let (offset, y_scale, c_scale) = range.scale();
+
+		let (red, green, blue) = (
+			self.red as f64 / 255.,
+			self.green as f64 / 255.,
+			self.blue as f64 / 255.,
+		);
+		let y = kr * red + (1. - kr - kb) * green + kb * blue;
+		let pb = (blue - y) / (2. * (1. - kb));
+		let pr = (red - y) / (2. * (1. - kr));
+
+		let apply = |v: f64| v.clamp(0., 255.).round() as u8;
+		YCbCr::new(
+			apply(offset + y_scale * y),
+			apply(128. + c_scale * pb),
+			apply(128. + c_scale * pr),
+		)
+	}
+
+	///
+	/// Returns the ANSI escape sequence that sets the terminal's foreground
+	/// to this color using 24-bit true color.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let fg = Rgb::new(0, 255, 255).ansi_fg();
+	/// assert_eq!(fg, String::from("\x1b[38;2;0;255;255m"));
+	/// ```
+	///
+	pub fn ansi_fg(self) -> String {
+		format!("\x1b[38;2;{};{};{}m", self.red, self.green, self.blue)
+	}
+
+	///
+	/// Returns the ANSI escape sequence that sets the terminal's background
+	/// to this color using 24-bit true color.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let bg = Rgb::new(0, 255, 255).ansi_bg();
+	/// assert_eq!(bg, String::from("\x1b[48;2;0;255;255m"));
+	/// ```
+	///
+	pub fn ansi_bg(self) -> String {
+		format!("\x1b[48;2;{};{};{}m", self.red, self.green, self.blue)
+	}
+
+	///
+	/// Returns the index of the closest color in the xterm 256-color palette
+	/// by Euclidean distance in RGB space. Only the 6×6×6 color cube
+	/// (indices 16 to 231) and the grayscale ramp (indices 232 to 255) are
+	/// considered, since the first 16 colors vary between terminals.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// assert_eq!(Rgb::new(255, 0, 0).to_ansi256(), 196);
+	/// assert_eq!(Rgb::new(128, 128, 128).to_ansi256(), 244);
+	/// ```
+	///
+	pub fn to_ansi256(self) -> u8 {
+		const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+		let nearest_level = |channel: u8| {
+			(0..CUBE_LEVELS.len())
+				.min_by_key(|&i| (CUBE_LEVELS[i] as i32 - channel as i32).abs())
+				.unwrap()
+		};
+		let (r, g, b) = (
+			nearest_level(self.red),
+			nearest_level(self.green),
+			nearest_level(self.blue),
+		);
+		let cube = Rgb::new(CUBE_LEVELS[r], CUBE_LEVELS[g], CUBE_LEVELS[b]);
+
+		let average = (self.red as i32 + self.green as i32 + self.blue as i32) / 3;
+		let gray_index = ((average - 8 + 5) / 10).clamp(0, 23);
+		let gray_level = (8 + gray_index * 10) as u8;
+		let gray = Rgb::new(gray_level, gray_level, gray_level);
+
+		if self._distance(gray) < self._distance(cube) {
+			232 + gray_index as u8
+		} else {
+			16 + (36 * r + 6 * g + b) as u8
+		}
+	}
+
+	///
+	/// Returns the ANSI escape sequence that sets the terminal's foreground
+	/// to the closest color in the xterm 256-color palette, for terminals
+	/// without true color support. See [`Rgb::to_ansi256`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let fg = Rgb::new(255, 0, 0).ansi256_fg();
+	/// assert_eq!(fg, String::from("\x1b[38;5;196m"));
+	/// ```
+	///
+	pub fn ansi256_fg(self) -> String {
+		format!("\x1b[38;5;{}m", self.to_ansi256())
+	}
+
+	///
+	/// Returns the approximate color of a blackbody radiator at the given
+	/// temperature in Kelvin, using Tanner Helland's approximation. The
+	/// temperature is clamped to the supported range of 1000 K to 40000 K.
+	/// Around 6500 K is close to neutral white, with lower temperatures being
+	/// warmer (orange) and higher temperatures being cooler (blue).
+	///
+	/// # Arguments
+	///
+	/// * `kelvin` - the color temperature in Kelvin
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let candlelight = Rgb::from_temperature(1900);
+	/// assert_eq!(candlelight, Rgb::new(255, 132, 0));
+	/// ```
+	///
+	pub fn from_temperature(kelvin: u16) -> Self {
+		let temperature = kelvin.clamp(1000, 40000) as f64 / 100.;
+
+		let red = if temperature <= 66. {
+			255.
+		} else {
+			329.698727446 * (temperature - 60.).powf(-0.1332047592)
+		};
+
+		let green = if temperature <= 66. {
+			99.4708025861 * temperature.ln() - 161.1195681661
+		} else {
+			288.1221695283 * (temperature - 60.).powf(-0.0755148492)
+		};
+
+		let blue = if temperature >= 66. {
+			255.
+		} else if temperature <= 19. {
+			0.
+		} else {
+			138.5177312231 * (temperature - 10.).ln() - 305.0447927307
+		};
+
+		let apply = |v: f64| v.clamp(0., 255.).round() as u8;
+		Self::new(apply(red), apply(green), apply(blue))
+	}
+
+	///
+	/// Generates a uniformly random color, with each channel drawn
+	/// independently from `rng`.
+	///
+	/// # Arguments
+	///
+	/// * `rng` - the random number generator to draw from
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	/// use rand::SeedableRng;
+	/// use rand_chacha::ChaCha8Rng;
+	///
+	/// let mut rng = ChaCha8Rng::seed_from_u64(0);
+	/// let color = Rgb::random(&mut rng);
+	/// ```
+	///
+	#[cfg(feature = "rand")]
+	pub fn random<R: rand::Rng>(rng: &mut R) -> Rgb {
+		Rgb::new(rng.gen(), rng.gen(), rng.gen())
+	}
+
+	///
+	/// Linearly interpolates between `self` and `other` by the factor `t`,
+	/// which is clamped to the range `[0.0, 1.0]`. A `t` of `0.0` returns
+	/// `self` and a `t` of `1.0` returns `other`.
+	///
+	/// # Arguments
+	///
+	/// * `other` - the color to interpolate towards
+	/// * `t` - the interpolation factor
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let black = Rgb::new(0, 0, 0);
+	/// let white = Rgb::new(255, 255, 255);
+	///
+	/// assert_eq!(black.lerp(white, 0.5), Rgb::new(128, 128, 128));
+	/// ```
+	///
+	pub fn lerp(self, other: Rgb, t: f64) -> Rgb {
+		let t = t.clamp(0., 1.);
+		let apply = |from: u8, to: u8| (from as f64 + (to as f64 - from as f64) * t).round() as u8;
+
+		let red = apply(self.red, other.red);
+		let green = apply(self.green, other.green);
+		let blue = apply(self.blue, other.blue);
+
+		Rgb { red, green, blue }
+	}
+
+	///
+	/// Returns the color halfway between `self` and `other`. Equivalent to
+	/// calling [`Rgb::lerp`] with a `t` of `0.5`.
+	///
+	/// # Arguments
+	///
+	/// * `other` - the other color
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let black = Rgb::new(0, 0, 0);
+	/// let white = Rgb::new(255, 255, 255);
+	///
+	/// assert_eq!(black.midpoint(white), Rgb::new(128, 128, 128));
+	/// ```
+	///
+	pub fn midpoint(self, other: Rgb) -> Rgb {
+		self.lerp(other, 0.5)
+	}
+
+	///
+	/// Returns a tint of the color by mixing it with white, lightening it. An
+	/// `amount` of `0.0` returns the color unchanged and `1.0` returns white.
+	/// See [`Rgb::lerp`].
+	///
+	/// # Arguments
+	///
+	/// * `amount` - how much to mix in, clamped to the range `[0.0, 1.0]`
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// assert_eq!(Rgb::new(255, 0, 0).tint(0.5), Rgb::new(255, 128, 128));
+	/// ```
+	///
+	pub fn tint(self, amount: f64) -> Rgb {
+		self.lerp(Rgb::new(255, 255, 255), amount)
+	}
+
+	///
+	/// Returns a shade of the color by mixing it with black, darkening it. An
+	/// `amount` of `0.0` returns the color unchanged and `1.0` returns black.
+	/// See [`Rgb::lerp`].
+	///
+	/// # Arguments
+	///
+	/// * `amount` - how much to mix in, clamped to the range `[0.0, 1.0]`
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// assert_eq!(Rgb::new(255, 0, 0).shade(0.5), Rgb::new(128, 0, 0));
+	/// ```
+	///
+	pub fn shade(self, amount: f64) -> Rgb {
+		self.lerp(Rgb::new(0, 0, 0), amount)
+	}
+
+	///
+	/// Returns a tone of the color by mixing it with mid-gray
+	/// (`rgb(128, 128, 128)`), desaturating it. An `amount` of `0.0` returns
+	/// the color unchanged and `1.0` returns mid-gray. See [`Rgb::lerp`].
+	///
+	/// # Arguments
+	///
+	/// * `amount` - how much to mix in, clamped to the range `[0.0, 1.0]`
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// assert_eq!(Rgb::new(255, 0, 0).tone(0.5), Rgb::new(192, 64, 64));
+	/// ```
+	///
+	pub fn tone(self, amount: f64) -> Rgb {
+		self.lerp(Rgb::new(128, 128, 128), amount)
+	}
+
+	///
+	/// Returns a lazy iterator over `steps` evenly spaced colors between
+	/// `self` and `other`, inclusive of both endpoints. A `steps` of `1`
+	/// yields only `self` and a `steps` of `0` yields nothing.
+	///
+	/// # Arguments
+	///
+	/// * `other` - the color to end the gradient at
+	/// * `steps` - the number of colors to yield
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let black = Rgb::new(0, 0, 0);
+	/// let white = Rgb::new(255, 255, 255);
+	/// let gradient = black.gradient(white, 3).collect::<Vec<_>>();
+	///
+	/// assert_eq!(gradient, vec![black, Rgb::new(128, 128, 128), white]);
+	/// ```
+	///
+	pub fn gradient(self, other: Rgb, steps: usize) -> impl Iterator<Item = Rgb> {
+		let last = steps.saturating_sub(1).max(1) as f64;
+		(0..steps).map(move |step| self.lerp(other, step as f64 / last))
+	}
+
+	///
+	/// Mixes `self` and `other` by the factor `t`, which is clamped to the
+	/// range `[0.0, 1.0]`. Unlike [`Rgb::lerp`], the interpolation happens in
+	/// linear light rather than on the gamma-encoded channels, which avoids
+	/// the dark, muddy midpoints of naive averaging.
+	///
+	/// # Arguments
+	///
+	/// * `other` - the color to mix towards
+	/// * `t` - the mixing factor
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let red = Rgb::new(255, 0, 0);
+	/// let green = Rgb::new(0, 255, 0);
+	///
+	/// assert_eq!(red.mix(green, 0.5), Rgb::new(188, 188, 0));
+	/// ```
+	///
+	pub fn mix(self, other: Rgb, t: f64) -> Rgb {
+		let t = t.clamp(0., 1.);
+		let apply = |from: u8, to: u8| {
+			let (from, to) = (srgb_to_linear(from), srgb_to_linear(to));
+			linear_to_srgb(from + (to - from) * t)
+		};
+
+		let red = apply(self.red, other.red);
+		let green = apply(self.green, other.green);
+		let blue = apply(self.blue, other.blue);
+
+		Rgb { red, green, blue }
+	}
+
+	///
+	/// Returns a CSS `color-mix()` expression that mixes `self` with `other`
+	/// in the given color space, mirroring [`Rgb::lerp`] and [`Rgb::mix`]: a
+	/// `t` of `0.0` is all `self` and a `t` of `1.0` is all `other`. The
+	/// colors are written as hex codes and the space, such as `srgb`,
+	/// `srgb-linear`, or `oklab`, is emitted as given.
+	///
+	/// # Arguments
+	///
+	/// * `other` - the color to mix towards
+	/// * `t` - the proportion of `other`, clamped to the range `[0.0, 1.0]`
+	/// * `space` - the CSS color space to mix in
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let red = Rgb::new(255, 0, 0);
+	/// let blue = Rgb::new(0, 0, 255);
+	///
+	/// assert_eq!(
+	///     red.to_color_mix_string(blue, 0.5, "srgb"),
+	///     String::from("color-mix(in srgb, #ff0000 50%, #0000ff)")
+	/// );
+	/// ```
+	///
+	pub fn to_color_mix_string(self, other: Rgb, t: f64, space: &str) -> String {
+		// Percentages are rounded to two decimal places, dropping any
+		// trailing zeros
+		let percentage = ((1. - t.clamp(0., 1.)) * 10_000.).round() / 100.;
+
+		format!(
+			"color-mix(in {}, {} {}%, {})",
+			space,
+			self.to_hex_string(),
+			percentage,
+			other.to_hex_string()
+		)
+	}
+
+	///
+	/// Returns the relative luminance of the color as defined by WCAG 2.1,
+	/// ranging from `0.0` for black to `1.0` for white.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// assert_eq!(Rgb::new(0, 0, 0).relative_luminance(), 0.);
+	/// assert_eq!(Rgb::new(255, 255, 255).relative_luminance(), 1.);
+	/// ```
+	///
+	pub fn relative_luminance(self) -> f64 {
+		let [red, green, blue] = self.to_linear();
+		0.2126 * red + 0.7152 * green + 0.0722 * blue
+	}
+
+	///
+	/// Returns the weighted average of `colors`, computed in linear light so
+	/// that the result matches how the colors would physically mix (see
+	/// [`Rgb::mix`]). The weights are normalized internally and need not sum
+	/// to `1.0`. Returns black if `colors` is empty or the weights do not sum
+	/// to a positive number.
+	///
+	/// # Arguments
+	///
+	/// * `colors` - the colors to average, each paired with its weight
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let red = Rgb::new(255, 0, 0);
+	/// let green = Rgb::new(0, 255, 0);
+	///
+	/// assert_eq!(Rgb::average(&[(red, 1.), (green, 1.)]), red.mix(green, 0.5));
+	/// assert_eq!(Rgb::average(&[]), Rgb::new(0, 0, 0));
+	/// ```
+	///
+	pub fn average(colors: &[(Rgb, f64)]) -> Rgb {
+		let total: f64 = colors.iter().map(|&(_, weight)| weight).sum();
+		if total <= 0. {
+			return Rgb::new(0, 0, 0);
+		}
+
+		let mut sum = [0.; 3];
+		for &(rgb, weight) in colors {
+			for (sum, channel) in sum.iter_mut().zip(rgb.to_linear().iter()) {
+				*sum += channel * weight / total;
+			}
+		}
+
+		Rgb::from_linear(sum)
+	}
+
+	///
+	/// Reduces the chroma of the color by `amount` while keeping its
+	/// [relative luminance](Rgb::relative_luminance) constant, unlike
+	/// desaturating in HSL, which shifts perceived brightness. The channels
+	/// are moved towards the luminance in linear light, so an `amount` of
+	/// `1.0` gives the gray with the same luminance as the color.
+	///
+	/// # Arguments
+	///
+	/// * `amount` - how much to desaturate, clamped to the range `[0.0, 1.0]`
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let gray = Rgb::new(255, 0, 0).desaturate_preserving_luminance(1.);
+	/// assert_eq!(gray, Rgb::new(127, 127, 127));
+	/// ```
+	///
+	pub fn desaturate_preserving_luminance(self, amount: f64) -> Rgb {
+		let amount = amount.clamp(0., 1.);
+		let luminance = self.relative_luminance();
+		let [red, green, blue] = self.to_linear();
+		let apply = |channel: f64| channel + (luminance - channel) * amount;
+
+		Rgb::from_linear([apply(red), apply(green), apply(blue)])
+	}
+
+	///
+	/// Decodes each sRGB channel into linear light using the piecewise sRGB
+	/// transfer function, returning values from `0.0` to `1.0` ordered R, G,
+	/// B. See [`Rgb::from_linear`] for the inverse.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let [red, green, blue] = Rgb::new(255, 0, 0).to_linear();
+	/// assert_eq!((red, green, blue), (1., 0., 0.));
+	/// ```
+	///
+	pub fn to_linear(self) -> [f64; 3] {
+		[
+			srgb_to_linear(self.red),
+			srgb_to_linear(self.green),
+			srgb_to_linear(self.blue),
+		]
+	}
+
+	///
+	/// Encodes linear light values back into an sRGB color, undoing
+	/// [`Rgb::to_linear`]. Each value is clamped to the range `0.0` to `1.0`.
+	///
+	/// # Arguments
+	///
+	/// * `linear` - the linear red, green, and blue values, in that order
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let rgb = Rgb::new(30, 50, 60);
+	/// assert_eq!(Rgb::from_linear(rgb.to_linear()), rgb);
+	/// ```
+	///
+	pub fn from_linear(linear: [f64; 3]) -> Rgb {
+		let [red, green, blue] = linear;
+		Rgb::new(
+			linear_to_srgb(red),
+			linear_to_srgb(green),
+			linear_to_srgb(blue),
+		)
+	}
+
+	///
+	/// Encodes linear light values back into an sRGB color. This is the old
+	/// name of [`Rgb::from_linear`].
+	///
+	/// # Arguments
+	///
+	/// * `linear` - the linear red, green, and blue values, in that order
+	///
+	/// # Examples
+	///
+	/// ```
+	/// # #![allow(deprecated)]
+	/// use color_conv::Rgb;
+	///
+	/// let rgb = Rgb::new(30, 50, 60);
+	/// assert_eq!(Rgb::to_srgb(rgb.to_linear()), rgb);
+	/// ```
+	///
+	#[deprecated(note = "renamed to `Rgb::from_linear`, like the other constructors")]
+	pub fn to_srgb(linear: [f64; 3]) -> Rgb {
+		Rgb::from_linear(linear)
+	}
+
+	///
+	/// Applies a simple power-law gamma encoding to each channel, raising its
+	/// normalized value to `1 / gamma`. See [`Rgb::gamma_decode`] for the
+	/// inverse.
+	///
+	/// # Arguments
+	///
+	/// * `gamma` - the gamma exponent, such as `2.2`
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let encoded = Rgb::new(64, 0, 255).gamma_encode(2.);
+	/// assert_eq!(encoded, Rgb::new(128, 0, 255));
+	/// ```
+	///
+	pub fn gamma_encode(self, gamma: f64) -> Rgb {
+		self._gamma(1. / gamma)
+	}
+
+	///
+	/// Applies a simple power-law gamma decoding to each channel, raising its
+	/// normalized value to `gamma`. See [`Rgb::gamma_encode`] for the inverse.
+	///
+	/// # Arguments
+	///
+	/// * `gamma` - the gamma exponent, such as `2.2`
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let decoded = Rgb::new(128, 0, 255).gamma_decode(2.);
+	/// assert_eq!(decoded, Rgb::new(64, 0, 255));
+	/// ```
+	///
+	pub fn gamma_decode(self, gamma: f64) -> Rgb {
+		self._gamma(gamma)
+	}
+
+	///
+	/// Returns a key for sorting colors by perceived brightness, from darkest
+	/// to lightest. The key is the [relative luminance](Rgb::relative_luminance)
+	/// scaled to the full range of a `u32`, since `f64` does not implement
+	/// [`Ord`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let mut colors = vec![
+	///     Rgb::new(255, 255, 255),
+	///     Rgb::new(0, 0, 255),
+	///     Rgb::new(0, 255, 0),
+	/// ];
+	/// colors.sort_by_key(|color| color.sort_key_by_luminance());
+	///
+	/// assert_eq!(
+	///     colors,
+	///     vec![
+	///         Rgb::new(0, 0, 255),
+	///         Rgb::new(0, 255, 0),
+	///         Rgb::new(255, 255, 255),
+	///     ]
+	/// );
+	/// ```
+	///
+	pub fn sort_key_by_luminance(self) -> u32 {
+		(self.relative_luminance() * u32::MAX as f64).round() as u32
+	}
+
+	///
+	/// Returns the WCAG 2.1 contrast ratio between `self` and `other`,
+	/// ranging from `1.0` for identical colors to `21.0` for black and white.
+	/// The order of the two colors does not matter.
+	///
+	/// # Arguments
+	///
+	/// * `other` - the color to compare against
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let black = Rgb::new(0, 0, 0);
+	/// let white = Rgb::new(255, 255, 255);
+	///
+	/// assert_eq!(black.contrast_ratio(white), 21.);
+	/// ```
+	///
+	pub fn contrast_ratio(self, other: Rgb) -> f64 {
+		let (self_luminance, other_luminance) =
+			(self.relative_luminance(), other.relative_luminance());
+		let lighter = self_luminance.max(other_luminance);
+		let darker = self_luminance.min(other_luminance);
+
+		(lighter + 0.05) / (darker + 0.05)
+	}
+
+	///
+	/// Lightens or darkens the color in HSL lightness, keeping its hue and
+	/// saturation, until its [contrast ratio](Rgb::contrast_ratio) against
+	/// `background` reaches `target_ratio`. The color is moved away from the
+	/// background first, and the other way only if that fails; the closest
+	/// lightness that meets the target is returned. If neither direction
+	/// can meet it, whichever of black or white contrasts more with the
+	/// background is returned. Colors that already meet the target are
+	/// returned unchanged.
+	///
+	/// # Arguments
+	///
+	/// * `background` - the color the result will be displayed against
+	/// * `target_ratio` - the contrast ratio to reach, such as `4.5`
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let white = Rgb::new(255, 255, 255);
+	/// let accent = Rgb::new(120, 170, 255).adjust_for_contrast(white, 4.5);
+	///
+	/// assert!(accent.contrast_ratio(white) >= 4.5);
+	/// ```
+	///
+	pub fn adjust_for_contrast(self, background: Rgb, target_ratio: f64) -> Rgb {
+		if self.contrast_ratio(background) >= target_ratio {
+			return self;
+		}
+
+		let Hsl {
+			hue,
+			saturation,
+			lightness,
+		} = self.to_hsl();
+		let candidate = |lightness| Hsl::new_unchecked(hue, saturation, lightness).to_rgb();
+		let meets_target = |rgb: &Rgb| rgb.contrast_ratio(background) >= target_ratio;
+
+		let lighter = || (lightness..=100).map(candidate).find(meets_target);
+		let darker = || (0..=lightness).rev().map(candidate).find(meets_target);
+
+		let adjusted = if self.relative_luminance() >= background.relative_luminance() {
+			lighter().or_else(darker)
+		} else {
+			darker().or_else(lighter)
+		};
+
+		adjusted.unwrap_or_else(|| {
+			let black = Rgb::new(0, 0, 0);
+			let white = Rgb::new(255, 255, 255);
+
+			if black.contrast_ratio(background) >= white.contrast_ratio(background) {
+				black
+			} else {
+				white
+			}
+		})
+	}
+
+	///
+	/// Returns whether the color is dark, meaning that white text on top of
+	/// it has a higher contrast ratio than black text. This happens when the
+	/// relative luminance is below roughly `0.179`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// assert!(Rgb::new(0, 0, 128).is_dark());
+	/// assert!(!Rgb::new(255, 255, 0).is_dark());
+	/// ```
+	///
+	pub fn is_dark(self) -> bool {
+		let black = Rgb::new(0, 0, 0);
+		let white = Rgb::new(255, 255, 255);
+
+		self.contrast_ratio(white) > self.contrast_ratio(black)
+	}
+
+	///
+	/// Returns whether the color is light. This is the opposite of
+	/// [`Rgb::is_dark`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// assert!(Rgb::new(255, 255, 0).is_light());
+	/// assert!(!Rgb::new(0, 0, 128).is_light());
+	/// ```
+	///
+	pub fn is_light(self) -> bool {
+		!self.is_dark()
+	}
+
+	///
+	/// Returns the perceived brightness of the color using the HSP model,
+	/// `sqrt(0.299 r² + 0.587 g² + 0.114 b²)`, ranging from `0.0` for black
+	/// to `255.0` for white. Unlike [`Rgb::relative_luminance`], this works
+	/// directly on the gamma-encoded channels.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// assert_eq!(Rgb::new(0, 0, 0).hsp_brightness(), 0.);
+	/// assert!((Rgb::new(255, 255, 255).hsp_brightness() - 255.).abs() < 1e-9);
+	/// ```
+	///
+	pub fn hsp_brightness(self) -> f64 {
+		let (red, green, blue) = (self.red as f64, self.green as f64, self.blue as f64);
+		(0.299 * red * red + 0.587 * green * green + 0.114 * blue * blue).sqrt()
+	}
+
+	///
+	/// Returns whether the color is light according to the HSP model,
+	/// meaning that its [`Rgb::hsp_brightness`] is above `127.5`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// assert!(Rgb::new(255, 255, 0).is_light_hsp());
+	/// assert!(!Rgb::new(0, 0, 255).is_light_hsp());
+	/// ```
+	///
+	pub fn is_light_hsp(self) -> bool {
+		self.hsp_brightness() > 127.5
+	}
+
+	///
+	/// Returns either black or white, whichever has the higher contrast
+	/// ratio against the color, for drawing readable text on top of it.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let text = Rgb::new(0, 0, 128).readable_text_color();
+	/// assert_eq!(text, Rgb::new(255, 255, 255));
+	/// ```
+	///
+	pub fn readable_text_color(self) -> Rgb {
+		if self.is_dark() {
+			Rgb::new(255, 255, 255)
+		} else {
+			Rgb::new(0, 0, 0)
+		}
+	}
+
+	///
+	/// Converts every color in `input` to [`Hsl`], in order. Equivalent to
+	/// mapping [`Color::to_hsl`] over the slice.
+	///
+	/// # Arguments
+	///
+	/// * `input` - the colors to convert
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::{Hsl, Rgb};
+	///
+	/// let converted = Rgb::slice_to_hsl(&[Rgb::new(0, 255, 255), Rgb::new(255, 0, 0)]);
+	/// assert_eq!(converted, vec![Hsl::new_unchecked(180, 100, 50), Hsl::new_unchecked(0, 100, 50)]);
+	/// ```
+	///
+	pub fn slice_to_hsl(input: &[Rgb]) -> Vec<Hsl> {
+		input.iter().map(|rgb| rgb.to_hsl()).collect()
+	}
+
+	///
+	/// Converts every color in `input` to [`Hsv`], in order. Equivalent to
+	/// mapping [`Color::to_hsv`] over the slice.
+	///
+	/// # Arguments
+	///
+	/// * `input` - the colors to convert
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::{Hsv, Rgb};
+	///
+	/// let converted = Rgb::slice_to_hsv(&[Rgb::new(0, 255, 255), Rgb::new(255, 0, 0)]);
+	/// assert_eq!(converted, vec![Hsv::new_unchecked(180, 100, 100), Hsv::new_unchecked(0, 100, 100)]);
+	/// ```
+	///
+	pub fn slice_to_hsv(input: &[Rgb]) -> Vec<Hsv> {
+		input.iter().map(|rgb| rgb.to_hsv()).collect()
+	}
+
+	///
+	/// Converts every color in `input` to [`Cmyk`], in order. Equivalent to
+	/// mapping [`Color::to_cmyk`] over the slice.
+	///
+	/// # Arguments
+	///
+	/// * `input` - the colors to convert
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::{Cmyk, Rgb};
+	///
+	/// let converted = Rgb::slice_to_cmyk(&[Rgb::new(0, 255, 255), Rgb::new(0, 0, 0)]);
+	/// assert_eq!(converted, vec![Cmyk::new_unchecked(100, 0, 0, 0), Cmyk::new_unchecked(0, 0, 0, 100)]);
+	/// ```
+	///
+	pub fn slice_to_cmyk(input: &[Rgb]) -> Vec<Cmyk> {
+		input.iter().map(|rgb| rgb.to_cmyk()).collect()
+	}
+
+	///
+	/// Converts every color in `input` to [`Hsl`], writing the results into
+	/// the matching positions of `output` without allocating. See
+	/// [`Rgb::slice_to_hsl`].
+	///
+	/// # Arguments
+	///
+	/// * `input` - the colors to convert
+	/// * `output` - the buffer to write into
+	///
+	/// # Panics
+	///
+	/// Panics if `input` and `output` have different lengths.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::{Hsl, Rgb};
+	///
+	/// let mut output = [Hsl::default(); 2];
+	/// Rgb::slice_to_hsl_into(&[Rgb::new(0, 255, 255), Rgb::new(255, 0, 0)], &mut output);
+	///
+	/// assert_eq!(output, [Hsl::new_unchecked(180, 100, 50), Hsl::new_unchecked(0, 100, 50)]);
+	/// ```
+	///
+	pub fn slice_to_hsl_into(input: &[Rgb], output: &mut [Hsl]) {
+		assert_eq!(
+			input.len(),
+			output.len(),
+			"input and output slices must have the same length"
+		);
+
+		for (rgb, hsl) in input.iter().zip(output.iter_mut()) {
+			*hsl = rgb.to_hsl();
+		}
+	}
+
+	///
+	/// Returns the channel with the largest value. Ties are broken in the
+	/// order red, green, blue, so gray colors report [`Channel::Red`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::{Channel, Rgb};
+	///
+	/// assert_eq!(Rgb::new(30, 50, 60).dominant_channel(), Channel::Blue);
+	/// assert_eq!(Rgb::new(0, 200, 200).dominant_channel(), Channel::Green);
+	/// ```
+	///
+	pub fn dominant_channel(self) -> Channel {
+		if self.red >= self.green && self.red >= self.blue {
+			Channel::Red
+		} else if self.green >= self.blue {
+			Channel::Green
+		} else {
+			Channel::Blue
+		}
+	}
+
+	///
+	/// Returns the value of the largest channel.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// assert_eq!(Rgb::new(30, 50, 60).max_channel(), 60);
+	/// ```
+	///
+	pub fn max_channel(self) -> u8 {
+		self.red.max(self.green).max(self.blue)
+	}
+
+	///
+	/// Returns the value of the smallest channel.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// assert_eq!(Rgb::new(30, 50, 60).min_channel(), 30);
+	/// ```
+	///
+	pub fn min_channel(self) -> u8 {
+		self.red.min(self.green).min(self.blue)
+	}
+
+	///
+	/// Returns the chroma of the color, the difference between its largest
+	/// and smallest channels. Grays have a chroma of `0`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// assert_eq!(Rgb::new(30, 50, 60).chroma(), 30);
+	/// assert_eq!(Rgb::new(128, 128, 128).chroma(), 0);
+	/// ```
+	///
+	pub fn chroma(self) -> u8 {
+		self.max_channel() - self.min_channel()
+	}
+
+	///
+	/// Collapses the color into a gray using perceptual luminance weights.
+	/// Equivalent to calling [`Rgb::to_grayscale_with`] with
+	/// [`GrayscaleMethod::Luminance`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let gray = Rgb::new(255, 0, 0).to_grayscale();
+	/// assert_eq!(gray, Rgb::new(54, 54, 54));
+	/// ```
+	///
+	pub fn to_grayscale(self) -> Rgb {
+		self.to_grayscale_with(GrayscaleMethod::Luminance)
+	}
+
+	///
+	/// Collapses the color into a gray, with all three channels being equal,
+	/// using the given [`GrayscaleMethod`].
+	///
+	/// # Arguments
+	///
+	/// * `method` - the algorithm used to compute the gray value
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::{GrayscaleMethod, Rgb};
+	///
+	/// let gray = Rgb::new(255, 0, 0).to_grayscale_with(GrayscaleMethod::Average);
+	/// assert_eq!(gray, Rgb::new(85, 85, 85));
+	/// ```
+	///
+	pub fn to_grayscale_with(self, method: GrayscaleMethod) -> Rgb {
+		let Self { red, green, blue } = self;
+
+		let gray = match method {
+			GrayscaleMethod::Average => (red as Float + green as Float + blue as Float) / 3.,
+			GrayscaleMethod::Luminance => {
+				0.2126 * red as Float + 0.7152 * green as Float + 0.0722 * blue as Float
+			}
+			GrayscaleMethod::Lightness => {
+				let (c_max, c_min) = self._extrema();
+				(c_max + c_min) / 2. * 255.
+			}
+		}
+		.round() as u8;
+
+		Rgb::new(gray, gray, gray)
+	}
+
+	///
+	/// Simulates how the color appears to someone with the given color vision
+	/// deficiency. The color is converted from linear light into LMS cone
+	/// space, the response of the missing cone is reconstructed from the
+	/// other two, and the result is converted back, following Viénot,
+	/// Brettel, and Mollon (1999).
+	///
+	/// # Arguments
+	///
+	/// * `kind` - the color vision deficiency to simulate
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::{CvdType, Rgb};
+	///
+	/// let red = Rgb::new(255, 0, 0);
+	/// let simulated = red.simulate_cvd(CvdType::Deuteranopia);
+	///
+	/// // Red and green become hard to tell apart, so red loses its hue
+	/// assert!(simulated.red < 200 && simulated.green > 100);
+	/// ```
+	///
+	pub fn simulate_cvd(self, kind: CvdType) -> Rgb {
+		const RGB_TO_LMS: [[f64; 3]; 3] = [
+			[0.31399022, 0.63951294, 0.04649755],
+			[0.15537241, 0.75789446, 0.08670142],
+			[0.01775239, 0.10944209, 0.87256922],
+		];
+		const LMS_TO_RGB: [[f64; 3]; 3] = [
+			[5.47221206, -4.6419601, 0.16963708],
+			[-1.1252419, 2.29317094, -0.1678952],
+			[0.02980165, -0.19318073, 1.16364789],
+		];
+
+		let multiply = |matrix: [[f64; 3]; 3], [x, y, z]: [f64; 3]| {
+			let row = |[a, b, c]: [f64; 3]| a * x + b * y + c * z;
+			[row(matrix[0]), row(matrix[1]), row(matrix[2])]
+		};
+
+		let [l, m, s] = multiply(RGB_TO_LMS, self.to_linear());
+		let simulated = match kind {
+			CvdType::Protanopia => [1.05118294 * m - 0.05116099 * s, m, s],
+			CvdType::Deuteranopia => [l, 0.9513092 * l + 0.04866992 * s, s],
+			CvdType::Tritanopia => [l, m, -0.86744736 * l + 1.86727089 * m],
+		};
+
+		Rgb::from_linear(multiply(LMS_TO_RGB, simulated))
+	}
+
+	///
+	/// Converts to HSL without rounding, returning the hue (in degrees, from
+	/// `0.0` up to but excluding `360.0`), saturation percentage, and
+	/// lightness percentage. Unlike [`Color::to_hsl`], the components are not
+	/// rounded, which avoids accumulating error across chained conversions.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let (hue, saturation, lightness) = Rgb::new(100, 150, 200).to_hsl_f64();
+	///
+	/// assert_eq!(hue, 210.);
+	/// assert!((saturation - 47.62).abs() < 0.01);
+	/// assert!((lightness - 58.82).abs() < 0.01);
+	/// ```
+	///
+	pub fn to_hsl_f64(self) -> (f64, f64, f64) {
+		_widen(self._hsl())
+	}
+
+	///
+	/// Converts to HSV without rounding, returning the hue (in degrees, from
+	/// `0.0` up to but excluding `360.0`), saturation percentage, and value
+	/// percentage. See [`Rgb::to_hsl_f64`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let (hue, saturation, value) = Rgb::new(100, 150, 200).to_hsv_f64();
+	///
+	/// assert_eq!(hue, 210.);
+	/// assert_eq!(saturation, 50.);
+	/// assert!((value - 78.43).abs() < 0.01);
+	/// ```
+	///
+	pub fn to_hsv_f64(self) -> (f64, f64, f64) {
+		_widen(self._hsv())
+	}
+
+	///
+	/// Converts to HWB without rounding, returning the hue (in degrees, from
+	/// `0.0` up to but excluding `360.0`), whiteness percentage, and
+	/// blackness percentage. See [`Rgb::to_hsl_f64`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let (hue, whiteness, blackness) = Rgb::new(100, 150, 200).to_hwb_f64();
+	///
+	/// assert_eq!(hue, 210.);
+	/// assert!((whiteness - 39.22).abs() < 0.01);
+	/// assert!((blackness - 21.57).abs() < 0.01);
+	/// ```
+	///
+	pub fn to_hwb_f64(self) -> (f64, f64, f64) {
+		_widen(self._hwb())
+	}
+
+	fn _gamma(self, exponent: f64) -> Rgb {
+		let apply = |channel: u8| ((channel as f64 / 255.).powf(exponent) * 255.).round() as u8;
+
+		let red = apply(self.red);
+		let green = apply(self.green);
+		let blue = apply(self.blue);
+
+		Rgb { red, green, blue }
+	}
+
+	fn _distance(self, other: Rgb) -> i32 {
+		[
+			self.red as i32 - other.red as i32,
+			self.green as i32 - other.green as i32,
+			self.blue as i32 - other.blue as i32,
+		]
+		.iter()
+		.map(|delta| delta * delta)
+		.sum()
+	}
+
+	fn _to_cmyk(self) -> (u8, u8, u8, u8) {
+		let r_prime = self.red as Float / 255.;
+		let g_prime = self.green as Float / 255.;
+		let b_prime = self.blue as Float / 255.;
+
+		let key = 1. - self.max_channel() as Float / 255.;
+
+		// Pure black has no defined cyan, magenta, or yellow since computing
+		// them would divide by zero
+		if (1. - key).abs() < Float::EPSILON {
+			return (0, 0, 0, 100);
+		}
+
+		let apply = |v: Float| (((1. - v - key) / (1. - key)) * 100.).round();
+		let cyan = apply(r_prime);
+		let magenta = apply(g_prime);
+		let yellow = apply(b_prime);
+
+		(
+			cyan as u8,
+			magenta as u8,
+			yellow as u8,
+			(key * 100.).round() as u8,
+		)
+	}
+
+	fn _extrema(self) -> (Float, Float) {
+		(
+			self.max_channel() as Float / 255.,
+			self.min_channel() as Float / 255.,
+		)
+	}
+
+	fn _hue(self) -> u16 {
+		self._precise_hue().round().rem_euclid(360.) as u16
+	}
+
+	fn _normalized(self) -> (Float, Float, Float) {
+		(
+			self.red as Float / 255.,
+			self.green as Float / 255.,
+			self.blue as Float / 255.,
+		)
+	}
+
+	fn _precise_hue(self) -> Float {
+		let (c_max, c_min) = self._extrema();
+		precise_hue(self._normalized(), c_max, c_min)
+	}
+
+	fn _hsl(self) -> (Float, Float, Float) {
+		let (c_max, c_min) = self._extrema();
+		hsl_components(self._normalized(), c_max, c_min)
+	}
+
+	fn _hsv(self) -> (Float, Float, Float) {
+		let (c_max, c_min) = self._extrema();
+		let delta = c_max - c_min;
+
+		let saturation = if c_max.abs() < Float::EPSILON {
+			0.
+		} else {
+			(delta / c_max) * 100.
+		};
+
+		(self._precise_hue(), saturation, c_max * 100.)
+	}
+
+	fn _hwb(self) -> (Float, Float, Float) {
+		let (c_max, c_min) = self._extrema();
+
+		(self._precise_hue(), c_min * 100., (1. - c_max) * 100.)
+	}
+}
+
+// `Float` is only narrower than `f64` under the `f32` feature
+#[allow(clippy::useless_conversion)]
+fn _widen((a, b, c): (Float, Float, Float)) -> (f64, f64, f64) {
+	(f64::from(a), f64::from(b), f64::from(c))
+}
+
+///
+/// Computes the hue (in degrees, from `0.0` up to but excluding `360.0`) from
+/// the red, green, and blue channels and their extrema, all normalized to
+/// `[0, 1]`.
+///
+#[inline]
+fn precise_hue((red, green, blue): (Float, Float, Float), c_max: Float, c_min: Float) -> Float {
+	let delta = c_max - c_min;
+
+	if delta.abs() < Float::EPSILON {
+		0.
+	} else {
+		match c_max {
+			x if x == red => 60. * (((green - blue) / delta) % 6.),
+			x if x == green => 60. * (((blue - red) / delta) + 2.),
+			x if x == blue => 60. * (((red - green) / delta) + 4.),
+			_ => panic!("Invalid hue calculation!"),
+		}
+		// Red-dominant colors with more blue than green produce a negative
+		// hue, so wrap it back into [0, 360)
+		.rem_euclid(360.)
+	}
+}
+
+///
+/// Computes the unrounded hue, saturation percentage, and lightness
+/// percentage from normalized channels and their extrema (see
+/// [`precise_hue`]).
+///
+#[inline]
+fn hsl_components(
+	channels: (Float, Float, Float),
+	c_max: Float,
+	c_min: Float,
+) -> (Float, Float, Float) {
+	let delta = c_max - c_min;
+
+	let lightness = (c_max + c_min) / 2.;
+
+	let saturation = if delta.abs() < Float::EPSILON {
+		0.
+	} else {
+		delta / (1. - ((2. * lightness) - 1.).abs()) * 100.
+	};
+
+	(
+		precise_hue(channels, c_max, c_min),
+		saturation,
+		lightness * 100.,
+	)
+}
+
+///
+/// Converts normalized channels and their extrema (see [`precise_hue`]) to
+/// [`Hsl`].
+///
+#[inline]
+fn normalized_to_hsl(channels: (Float, Float, Float), c_max: Float, c_min: Float) -> Hsl {
+	round_hsl(hsl_components(channels, c_max, c_min))
+}
+
+///
+/// Rounds an unrounded hue, saturation percentage, and lightness percentage
+/// to [`Hsl`], wrapping a hue that rounds up to `360` back to `0`. Shared with
+/// the lane-wise bulk conversion so that the two always agree.
+///
+#[inline]
+pub(crate) fn round_hsl((hue, saturation, lightness): (Float, Float, Float)) -> Hsl {
+	Hsl::new_unchecked(
+		hue.round().rem_euclid(360.) as u16,
+		saturation.round() as u8,
+		lightness.round() as u8,
+	)
+}
+
+///
+/// Returns the Euclidean distance between two colors in RGB space.
+///
+pub(crate) fn distance(a: Rgb, b: Rgb) -> f64 {
+	(a._distance(b) as f64).sqrt()
+}
+
+///
+/// Expands a gamma-encoded sRGB channel into linear light, ranging from `0.0`
+/// to `1.0`.
+///
+pub(crate) fn srgb_to_linear(channel: u8) -> f64 {
+	let c = channel as f64 / 255.;
+
+	if c <= 0.04045 {
+		c / 12.92
+	} else {
+		((c + 0.055) / 1.055).powf(2.4)
+	}
+}
+
+///
+/// Compresses a linear light value, clamped to the range `0.0` to `1.0`, into
+/// a gamma-encoded sRGB channel.
+///
+pub(crate) fn linear_to_srgb(linear: f64) -> u8 {
+	let c = linear.clamp(0., 1.);
+
+	let encoded = if c <= 0.0031308 {
+		c * 12.92
+	} else {
+		1.055 * c.powf(1. / 2.4) - 0.055
+	};
+
+	(encoded * 255.).round() as u8
+}
+
+///
+/// Defaults to black, `rgb(0, 0, 0)`.
+///
+impl Default for Rgb {
+	fn default() -> Self {
+		Self::new(0, 0, 0)
+	}
+}
+
+///
+/// Formats the color as `rgb(r, g, b)`, or as its hex code (see
+/// [`Color::to_hex_string`]) with the alternate flag, i.e. `{:#}`.
+///
+impl fmt::Display for Rgb {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if f.alternate() {
+			write!(f, "#{:0>2x}{:0>2x}{:0>2x}", self.red, self.green, self.blue)
+		} else {
+			write!(f, "rgb({}, {}, {})", self.red, self.green, self.blue)
+		}
+	}
+}
+
+impl FromStr for Rgb {
+	type Err = Error;
+
+	///
+	/// Parses a string of the form `rgb(r, g, b)`, as produced by the
+	/// [`Display`](core::fmt::Display) implementation of [`Rgb`]. Whitespace
+	/// around each channel is ignored. As in CSS, the channels may instead
+	/// all be percentages from `0%` to `100%`, which are scaled to 0 to 255,
+	/// but the two forms cannot be mixed.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgb;
+	///
+	/// let cyan = "rgb(0, 255, 255)".parse::<Rgb>()?;
+	/// assert_eq!(cyan, Rgb::new(0, 255, 255));
+	///
+	/// let cyan = "rgb(0%, 100%, 100%)".parse::<Rgb>()?;
+	/// assert_eq!(cyan, Rgb::new(0, 255, 255));
+	/// # Ok::<(), color_conv::Error>(())
+	/// ```
+	///
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let channels = parse::rgb_channels(&parse::function_args(s, "rgb")?)?;
+
+		match channels[..] {
+			[red, green, blue] => Ok(Self::new(red, green, blue)),
+			_ => Err(Error::ParseError(format!(
+				"expected 3 channels, got {}",
+				channels.len()
+			))),
+		}
+	}
+}
+
+///
+/// Adds the channels of two colors, as when mixing light. Each channel
+/// saturates at 255 rather than wrapping around.
+///
+impl Add for Rgb {
+	type Output = Rgb;
+
+	fn add(self, other: Rgb) -> Rgb {
+		Rgb::new(
+			self.red.saturating_add(other.red),
+			self.green.saturating_add(other.green),
+			self.blue.saturating_add(other.blue),
+		)
+	}
+}
+
+///
+/// Subtracts the channels of `other` from those of `self`. Each channel
+/// saturates at 0 rather than wrapping around.
+///
+impl Sub for Rgb {
+	type Output = Rgb;
+
+	fn sub(self, other: Rgb) -> Rgb {
+		Rgb::new(
+			self.red.saturating_sub(other.red),
+			self.green.saturating_sub(other.green),
+			self.blue.saturating_sub(other.blue),
+		)
+	}
+}
+
+///
+/// Scales the brightness of the color by multiplying each channel by a
+/// factor. The result is rounded and clamped to the range 0 to 255 rather
+/// than wrapping around, so negative factors give black.
+///
+impl Mul<f64> for Rgb {
+	type Output = Rgb;
+
+	fn mul(self, factor: f64) -> Rgb {
+		let apply = |channel: u8| (channel as f64 * factor).clamp(0., 255.).round() as u8;
+		Rgb::new(apply(self.red), apply(self.green), apply(self.blue))
+	}
+}
+
+impl From<Cmyk> for Rgb {
+	fn from(cmyk: Cmyk) -> Self {
+		cmyk.to_rgb()
+	}
+}
+
+impl From<Hsl> for Rgb {
+	fn from(hsl: Hsl) -> Self {
+		hsl.to_rgb()
+	}
+}
+
+impl From<Hsv> for Rgb {
+	fn from(hsv: Hsv) -> Self {
+		hsv.to_rgb()
+	}
+}
+
+impl Color for Rgb {
+	fn to_rgb(self) -> Rgb {
+		self
+	}
+
+	fn to_hex_string(self) -> String {
+		self.to_hex_string_with(HexOptions::default())
+	}
+
+	fn to_cmyk(self) -> Cmyk {
+		let (c, m, y, k) = self._to_cmyk();
+		Cmyk::new_unchecked(c, m, y, k)
+	}
+
+	fn to_hsl(self) -> Hsl {
+		let (c_max, c_min) = self._extrema();
+		normalized_to_hsl(self._normalized(), c_max, c_min)
+	}
+
+	fn to_hsv(self) -> Hsv {
+		let (_, saturation, value) = self._hsv();
+		Hsv::new_unchecked(self._hue(), saturation.round() as u8, value.round() as u8)
+	}
+
+	fn to_xyz(self) -> Xyz {
+		let red = srgb_to_linear(self.red);
+		let green = srgb_to_linear(self.green);
+		let blue = srgb_to_linear(self.blue);
+
+		Xyz::new(
+			(0.4124564 * red + 0.3575761 * green + 0.1804375 * blue) * 100.,
+			(0.2126729 * red + 0.7151522 * green + 0.072175 * blue) * 100.,
+			(0.0193339 * red + 0.119192 * green + 0.9503041 * blue) * 100.,
+		)
+	}
+
+	fn to_lab(self) -> Lab {
+		self.to_xyz().to_lab()
+	}
+
+	fn to_hwb(self) -> Hwb {
+		let (_, whiteness, blackness) = self._hwb();
+		Hwb::new_unchecked(
+			self._hue(),
+			whiteness.round() as u8,
+			blackness.round() as u8,
+		)
+	}
+}
+
+impl Invert for Rgb {
+	fn invert(self) -> Rgb {
+		Rgb::new(255 - self.red, 255 - self.green, 255 - self.blue)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_to_string() {
+		let rgb = Rgb::new(30, 50, 60);
+		assert_eq!(rgb.to_string(), String::from("rgb(30, 50, 60)"));
+	}
+
+	#[test]
+	fn test_from_str() {
+		let rgb = "rgb(30, 50, 60)".parse::<Rgb>().unwrap();
+		assert_eq!(rgb, Rgb::new(30, 50, 60));
+	}
+
+	#[test]
+	fn test_from_str_percentages() {
+		let rgb = "rgb(100%, 0%, 50%)".parse::<Rgb>().unwrap();
+		assert_eq!(rgb, Rgb::new(255, 0, 128));
+
+		let rgb = "rgb( 12.5% ,20%,   100% )".parse::<Rgb>().unwrap();
+		assert_eq!(rgb, Rgb::new(32, 51, 255));
+	}
+
+	#[test]
+	fn test_from_str_percentages_invalid() {
+		for input in [
+			"rgb(100%, 0, 50%)",
+			"rgb(255, 0%, 128)",
+			"rgb(101%, 0%, 0%)",
+			"rgb(-1%, 0%, 0%)",
+			"rgb(a%, 0%, 0%)",
+		]
+		.iter()
+		{
+			assert!(matches!(input.parse::<Rgb>(), Err(Error::ParseError(_))));
+		}
+	}
+
+	#[test]
+	fn test_from_str_whitespace() {
+		let rgb = "  rgb( 30 ,50,   60 ) ".parse::<Rgb>().unwrap();
+		assert_eq!(rgb, Rgb::new(30, 50, 60));
+	}
+
+	#[test]
+	fn test_from_str_out_of_range() {
+		let err = "rgb(256, 0, 0)".parse::<Rgb>().unwrap_err();
+		assert!(matches!(err, Error::ParseError(_)));
+	}
+
+	#[test]
+	fn test_from_str_garbage() {
+		for input in [
+			"",
+			"rgb",
+			"rgb(1, 2)",
+			"rgb(1, 2, 3, 4)",
+			"rgb(a, b, c)",
+			"hsl(1, 2, 3)",
+		]
+		.iter()
+		{
+			assert!(matches!(input.parse::<Rgb>(), Err(Error::ParseError(_))));
+		}
+	}
+
+	#[test]
+	fn test_try_new() {
+		assert_eq!(Rgb::try_new(30, 50, 60).unwrap(), Rgb::new(30, 50, 60));
+		assert_eq!(Rgb::try_new(0, 0, 0).unwrap(), Rgb::new(0, 0, 0));
+		assert_eq!(
+			Rgb::try_new(255, 255, 255).unwrap(),
+			Rgb::new(255, 255, 255)
+		);
+	}
+
+	#[test]
+	fn test_try_new_out_of_range() {
+		assert!(matches!(
+			Rgb::try_new(-1, 0, 0),
+			Err(Error::ChannelOverflow)
+		));
+		assert!(matches!(
+			Rgb::try_new(0, -200, 0),
+			Err(Error::ChannelOverflow)
+		));
+		assert!(matches!(
+			Rgb::try_new(0, 0, 256),
+			Err(Error::ChannelOverflow)
+		));
+		assert!(matches!(
+			Rgb::try_new(1000, 0, 0),
+			Err(Error::ChannelOverflow)
+		));
+	}
+
+	#[test]
+	fn test_to_hex_string() {
+		let hex = Rgb::new(30, 50, 60).to_hex_string();
+		assert_eq!(hex, String::from("#1e323c"));
+	}
+
+	#[test]
+	fn test_from_hex_str() {
+		let rgb = Rgb::new(30, 50, 60);
+		assert_eq!(Rgb::from_hex_str("#1e323c").unwrap(), rgb);
+		assert_eq!(Rgb::from_hex_str("1E323C").unwrap(), rgb);
+		assert_eq!(Rgb::from_hex_str(&rgb.to_hex_string()).unwrap(), rgb);
+	}
+
+	#[test]
+	fn test_from_hex_str_shorthand() {
+		let rgb = Rgb::new(0xaa, 0xbb, 0xcc);
+		assert_eq!(Rgb::from_hex_str("#abc").unwrap(), rgb);
+		assert_eq!(Rgb::from_hex_str("abc").unwrap(), rgb);
+	}
+
+	#[test]
+	fn test_from_hex_str_invalid() {
+		for input in ["#gg0000", "#ffff", "", "#", "#+f0000"].iter() {
+			assert!(matches!(Rgb::from_hex_str(input), Err(Error::InvalidHex)));
+		}
+	}
+
+	#[test]
+	fn test_to_cmyk() {
+		let rgb = Rgb::new(30, 50, 60).to_cmyk();
+		assert_eq!(rgb, Cmyk::new_unchecked(50, 17, 0, 76));
+	}
+
+	#[test]
+	fn test_to_hsl() {
+		let hsl = Rgb::new(204, 153, 102).to_hsl();
+		assert_eq!(hsl, Hsl::new_unchecked(30, 50, 60));
+	}
+
+	#[test]
+	fn test_to_hsv() {
+		let hsv = Rgb::new(204, 153, 102).to_hsv();
+		assert_eq!(hsv, Hsv::new_unchecked(30, 50, 80));
+
+		let red = Rgb::new(255, 0, 0).to_hsv();
+		assert_eq!(red, Hsv::new_unchecked(0, 100, 100));
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn test_serde() {
+		let rgb = Rgb::new(0, 255, 255);
+		let json = serde_json::to_string(&rgb).unwrap();
+		assert_eq!(json, r#"{"red":0,"green":255,"blue":255}"#);
+		assert_eq!(serde_json::from_str::<Rgb>(&json).unwrap(), rgb);
+	}
+
+	#[test]
+	fn test_lerp_endpoints() {
+		let from = Rgb::new(30, 50, 60);
+		let to = Rgb::new(204, 153, 102);
+		assert_eq!(from.lerp(to, 0.), from);
+		assert_eq!(from.lerp(to, 1.), to);
+	}
+
+	#[test]
+	fn test_lerp_midpoint() {
+		let midpoint = Rgb::new(0, 0, 0).lerp(Rgb::new(255, 255, 255), 0.5);
+		assert_eq!(midpoint, Rgb::new(128, 128, 128));
+	}
+
+	#[test]
+	fn test_lerp_clamped() {
+		let from = Rgb::new(30, 50, 60);
+		let to = Rgb::new(204, 153, 102);
+		assert_eq!(from.lerp(to, -1.), from);
+		assert_eq!(from.lerp(to, 2.), to);
+	}
+
+	#[test]
+	fn test_midpoint() {
+		let black = Rgb::new(0, 0, 0);
+		let white = Rgb::new(255, 255, 255);
+		assert_eq!(black.midpoint(white), Rgb::new(128, 128, 128));
+		assert_eq!(white.midpoint(black), Rgb::new(128, 128, 128));
+
+		let from = Rgb::new(30, 50, 60);
+		let to = Rgb::new(204, 153, 102);
+		assert_eq!(from.midpoint(to), Rgb::new(117, 102, 81));
+		assert_eq!(from.midpoint(to), from.lerp(to, 0.5));
+
+		let red = Rgb::new(255, 0, 0);
+		let blue = Rgb::new(0, 0, 255);
+		assert_eq!(red.midpoint(blue), Rgb::new(128, 0, 128));
+		assert_eq!(red.midpoint(red), red);
+	}
+
+	#[test]
+	fn test_gradient() {
+		let from = Rgb::new(30, 50, 60);
+		let to = Rgb::new(204, 153, 102);
+		let gradient = from.gradient(to, 5).collect::<Vec<_>>();
+
+		assert_eq!(gradient.len(), 5);
+		assert_eq!(gradient.first(), Some(&from));
+		assert_eq!(gradient.last(), Some(&to));
+	}
+
+	#[test]
+	fn test_gradient_few_steps() {
+		let from = Rgb::new(30, 50, 60);
+		let to = Rgb::new(204, 153, 102);
+
+		assert_eq!(from.gradient(to, 1).collect::<Vec<_>>(), vec![from]);
+		assert_eq!(from.gradient(to, 0).count(), 0);
+	}
+
+	#[test]
+	fn test_relative_luminance() {
+		assert!((Rgb::new(255, 0, 0).relative_luminance() - 0.2126).abs() < 1e-9);
+		assert!((Rgb::new(128, 128, 128).relative_luminance() - 0.2159).abs() < 1e-4);
+	}
+
+	#[test]
+	fn test_contrast_ratio() {
+		let black = Rgb::new(0, 0, 0);
+		let white = Rgb::new(255, 255, 255);
+
+		assert!((black.contrast_ratio(white) - 21.).abs() < f64::EPSILON);
+		assert!((white.contrast_ratio(black) - 21.).abs() < f64::EPSILON);
+		assert!((white.contrast_ratio(white) - 1.).abs() < f64::EPSILON);
+	}
+
+	#[test]
+	fn test_contrast_ratio_known_pairs() {
+		let white = Rgb::new(255, 255, 255);
+
+		assert!((Rgb::new(0x77, 0x77, 0x77).contrast_ratio(white) - 4.48).abs() < 0.01);
+		assert!((Rgb::new(255, 0, 0).contrast_ratio(white) - 4.).abs() < 0.01);
+		assert!((Rgb::new(0, 0, 255).contrast_ratio(white) - 8.59).abs() < 0.01);
+	}
+
+	#[test]
+	fn test_to_grayscale() {
+		let gray = Rgb::new(30, 50, 60).to_grayscale();
+		assert_eq!(gray.red, gray.green);
+		assert_eq!(gray.green, gray.blue);
+		assert_eq!(
+			gray,
+			Rgb::new(30, 50, 60).to_grayscale_with(GrayscaleMethod::Luminance)
+		);
+	}
+
+	#[test]
+	fn test_to_grayscale_with() {
+		let red = Rgb::new(255, 0, 0);
+		assert_eq!(
+			red.to_grayscale_with(GrayscaleMethod::Average),
+			Rgb::new(85, 85, 85)
+		);
+		assert_eq!(
+			red.to_grayscale_with(GrayscaleMethod::Luminance),
+			Rgb::new(54, 54, 54)
+		);
+		assert_eq!(
+			red.to_grayscale_with(GrayscaleMethod::Lightness),
+			Rgb::new(128, 128, 128)
+		);
+	}
+
+	#[test]
+	fn test_simulate_cvd() {
+		let red = Rgb::new(255, 0, 0);
+
+		for &(kind, expected) in [
+			(CvdType::Protanopia, Rgb::new(115, 115, 0)),
+			(CvdType::Deuteranopia, Rgb::new(156, 156, 0)),
+			(CvdType::Tritanopia, Rgb::new(255, 0, 0)),
+		]
+		.iter()
+		{
+			let simulated = red.simulate_cvd(kind);
+			assert!(
+				simulated._distance(expected) <= 3,
+				"{:?} gave {:?}",
+				kind,
+				simulated
+			);
+		}
+	}
+
+	#[test]
+	fn test_simulate_cvd_preserves_grays() {
+		for &gray in [0, 128, 255].iter() {
+			let rgb = Rgb::new(gray, gray, gray);
+			assert_eq!(rgb.simulate_cvd(CvdType::Protanopia), rgb);
+			assert_eq!(rgb.simulate_cvd(CvdType::Deuteranopia), rgb);
+			assert_eq!(rgb.simulate_cvd(CvdType::Tritanopia), rgb);
+		}
+	}
+
+	#[test]
+	fn test_hsp_brightness() {
+		let yellow = Rgb::new(255, 255, 0).hsp_brightness();
+		let blue = Rgb::new(0, 0, 255).hsp_brightness();
+
+		assert!((yellow - 240.02).abs() < 0.01);
+		assert!((blue - 86.10).abs() < 0.01);
+		assert!(yellow > 2. * blue);
+	}
+
+	#[test]
+	fn test_is_light_hsp() {
+		assert!(Rgb::new(255, 255, 255).is_light_hsp());
+		assert!(Rgb::new(255, 255, 0).is_light_hsp());
+		assert!(!Rgb::new(0, 0, 0).is_light_hsp());
+		assert!(!Rgb::new(0, 0, 255).is_light_hsp());
+		assert!(!Rgb::new(127, 127, 127).is_light_hsp());
+		assert!(Rgb::new(128, 128, 128).is_light_hsp());
+	}
+
+	#[test]
+	fn test_invert() {
+		assert_eq!(Rgb::new(0, 0, 0).invert(), Rgb::new(255, 255, 255));
+		assert_eq!(Rgb::new(255, 255, 255).invert(), Rgb::new(0, 0, 0));
+		assert_eq!(Rgb::new(30, 50, 60).invert(), Rgb::new(225, 205, 195));
 	}
 
 	#[test]
-	fn test_to_hsl() {
-		let hsl = Rgb::new(204, 153, 102).to_hsl();
-		assert_eq!(hsl, Hsl::new_unchecked(30, 50, 60));
+	fn test_double_invert() {
+		let rgb = Rgb::new(30, 50, 60);
+		assert_eq!(rgb.invert().invert(), rgb);
+	}
+
+	#[test]
+	fn test_black_to_cmyk() {
+		let cmyk = Rgb::new(0, 0, 0).to_cmyk();
+		assert_eq!(cmyk, Cmyk::new_unchecked(0, 0, 0, 100));
+	}
+
+	#[test]
+	fn test_to_hsl_red_with_more_blue() {
+		assert_eq!(Rgb::new(255, 0, 1).to_hsl().hue, 0);
+		assert_eq!(Rgb::new(255, 0, 128).to_hsl().hue, 330);
+
+		for blue in 1..=255 {
+			assert!(Rgb::new(255, 0, blue).to_hsl().hue < 360);
+		}
+	}
+
+	#[test]
+	fn test_from() {
+		let rgb: Rgb = Cmyk::new_unchecked(100, 0, 0, 0).into();
+		assert_eq!(rgb, Rgb::new(0, 255, 255));
+
+		let rgb: Rgb = Hsl::new_unchecked(180, 100, 50).into();
+		assert_eq!(rgb, Rgb::new(0, 255, 255));
+
+		let rgb: Rgb = Hsv::new_unchecked(180, 100, 100).into();
+		assert_eq!(rgb, Rgb::new(0, 255, 255));
+	}
+
+	#[test]
+	fn test_u32_round_trip() {
+		for rgb in [
+			Rgb::new(30, 50, 60),
+			Rgb::new(0, 0, 0),
+			Rgb::new(255, 255, 255),
+		]
+		.iter()
+		{
+			assert_eq!(Rgb::from_u32(rgb.to_u32()), *rgb);
+		}
+	}
+
+	#[test]
+	fn test_u32_byte_order() {
+		assert_eq!(Rgb::new(0x12, 0x34, 0x56).to_u32(), 0x00123456);
+		assert_eq!(Rgb::from_u32(0xff123456), Rgb::new(0x12, 0x34, 0x56));
+	}
+
+	#[test]
+	fn test_from_name() {
+		assert_eq!(
+			Rgb::from_name("rebeccapurple"),
+			Some(Rgb::new(102, 51, 153))
+		);
+		assert_eq!(
+			Rgb::from_name("cornflowerblue"),
+			Some(Rgb::new(100, 149, 237))
+		);
+		assert_eq!(Rgb::from_name("WHITE"), Some(Rgb::new(255, 255, 255)));
+		assert_eq!(Rgb::from_name("notacolor"), None);
+	}
+
+	#[test]
+	fn test_nearest_name() {
+		assert_eq!(Rgb::new(102, 51, 153).nearest_name(), "rebeccapurple");
+		assert_eq!(Rgb::new(128, 128, 128).nearest_name(), "gray");
+		assert_eq!(Rgb::new(101, 150, 235).nearest_name(), "cornflowerblue");
+	}
+
+	#[test]
+	fn test_from_temperature() {
+		let close = |actual: Rgb, expected: Rgb| {
+			(actual.red as i16 - expected.red as i16).abs() <= 2
+				&& (actual.green as i16 - expected.green as i16).abs() <= 2
+				&& (actual.blue as i16 - expected.blue as i16).abs() <= 2
+		};
+
+		assert!(close(Rgb::from_temperature(1000), Rgb::new(255, 68, 0)));
+		assert!(close(Rgb::from_temperature(6500), Rgb::new(255, 254, 250)));
+		assert!(close(Rgb::from_temperature(10000), Rgb::new(202, 218, 255)));
+	}
+
+	#[test]
+	fn test_from_temperature_clamped() {
+		assert_eq!(Rgb::from_temperature(0), Rgb::from_temperature(1000));
+		assert_eq!(
+			Rgb::from_temperature(u16::MAX),
+			Rgb::from_temperature(40000)
+		);
+	}
+
+	#[test]
+	fn test_precise_conversions() {
+		let rgb = Rgb::new(100, 150, 200);
+
+		let (hue, saturation, lightness) = rgb.to_hsl_f64();
+		assert!((saturation - 47.619).abs() < 0.001);
+		assert!((lightness - 58.824).abs() < 0.001);
+		assert_eq!(rgb.to_hsl(), Hsl::new_unchecked(210, 48, 59));
+		assert_eq!(hue, 210.);
+
+		let (_, saturation, value) = rgb.to_hsv_f64();
+		assert_eq!(saturation, 50.);
+		assert!((value - 78.431).abs() < 0.001);
+		assert_eq!(rgb.to_hsv(), Hsv::new_unchecked(210, 50, 78));
+
+		let (_, whiteness, blackness) = rgb.to_hwb_f64();
+		assert!((whiteness - 39.216).abs() < 0.001);
+		assert!((blackness - 21.569).abs() < 0.001);
+		assert_eq!(rgb.to_hwb(), Hwb::new_unchecked(210, 39, 22));
+	}
+
+	#[test]
+	fn test_precise_hue_wraps() {
+		// Red-dominant with more blue than green, so the raw hue is negative
+		let (hue, _, _) = Rgb::new(255, 0, 1).to_hsl_f64();
+		assert!(hue > 359. && hue < 360.);
+		assert_eq!(Rgb::new(255, 0, 1).to_hsl().hue, 0);
+	}
+
+	#[test]
+	fn test_to_hex_string_with() {
+		let rgb = Rgb::new(0, 171, 255);
+		let with = |uppercase, hash| rgb.to_hex_string_with(HexOptions { uppercase, hash });
+
+		assert_eq!(with(false, true), String::from("#00abff"));
+		assert_eq!(with(true, true), String::from("#00ABFF"));
+		assert_eq!(with(false, false), String::from("00abff"));
+		assert_eq!(with(true, false), String::from("00ABFF"));
+		assert_eq!(
+			rgb.to_hex_string_with(HexOptions::default()),
+			rgb.to_hex_string()
+		);
+		assert_eq!(rgb.to_hex_string_upper(), String::from("#00ABFF"));
+	}
+
+	#[test]
+	fn test_default() {
+		assert_eq!(Rgb::default(), Rgb::new(0, 0, 0));
+
+		let rgb = Rgb {
+			red: 5,
+			..Default::default()
+		};
+		assert_eq!(rgb, Rgb::new(5, 0, 0));
+	}
+
+	#[test]
+	fn test_mix_endpoints() {
+		let from = Rgb::new(30, 50, 60);
+		let to = Rgb::new(204, 153, 102);
+		assert_eq!(from.mix(to, 0.), from);
+		assert_eq!(from.mix(to, 1.), to);
+		assert_eq!(from.mix(to, -1.), from);
+		assert_eq!(from.mix(to, 2.), to);
+	}
+
+	#[test]
+	fn test_mix_brighter_than_lerp() {
+		let red = Rgb::new(255, 0, 0);
+		let green = Rgb::new(0, 255, 0);
+
+		let mixed = red.mix(green, 0.5);
+		let lerped = red.lerp(green, 0.5);
+
+		assert_eq!(mixed, Rgb::new(188, 188, 0));
+		assert_eq!(lerped, Rgb::new(128, 128, 0));
+		assert!(mixed.relative_luminance() > lerped.relative_luminance());
+	}
+
+	#[cfg(feature = "rand")]
+	#[test]
+	fn test_random_is_reproducible() {
+		use rand::SeedableRng;
+		use rand_chacha::ChaCha8Rng;
+
+		let colors = |seed| {
+			let mut rng = ChaCha8Rng::seed_from_u64(seed);
+			(0..8).map(|_| Rgb::random(&mut rng)).collect::<Vec<_>>()
+		};
+
+		assert_eq!(colors(42), colors(42));
+		assert_ne!(colors(42), colors(43));
+	}
+
+	#[test]
+	fn test_ansi_escapes() {
+		let rgb = Rgb::new(30, 50, 60);
+		assert_eq!(rgb.ansi_fg(), String::from("\x1b[38;2;30;50;60m"));
+		assert_eq!(rgb.ansi_bg(), String::from("\x1b[48;2;30;50;60m"));
+		assert_eq!(rgb.ansi256_fg(), String::from("\x1b[38;5;236m"));
+	}
+
+	#[test]
+	fn test_to_ansi256() {
+		assert_eq!(Rgb::new(0, 0, 0).to_ansi256(), 16);
+		assert_eq!(Rgb::new(255, 255, 255).to_ansi256(), 231);
+		assert_eq!(Rgb::new(255, 0, 0).to_ansi256(), 196);
+		assert_eq!(Rgb::new(95, 135, 175).to_ansi256(), 67);
+		assert_eq!(Rgb::new(100, 140, 170).to_ansi256(), 67);
+		assert_eq!(Rgb::new(128, 128, 128).to_ansi256(), 244);
+		assert_eq!(Rgb::new(238, 238, 238).to_ansi256(), 255);
+	}
+
+	#[test]
+	fn test_add() {
+		let red = Rgb::new(255, 0, 0);
+		let green = Rgb::new(0, 255, 0);
+		assert_eq!(red + green, Rgb::new(255, 255, 0));
+		assert_eq!(
+			Rgb::new(200, 100, 1) + Rgb::new(100, 100, 254),
+			Rgb::new(255, 200, 255)
+		);
+	}
+
+	#[test]
+	fn test_sub() {
+		let white = Rgb::new(255, 255, 255);
+		assert_eq!(white - Rgb::new(255, 0, 0), Rgb::new(0, 255, 255));
+		assert_eq!(
+			Rgb::new(100, 50, 0) - Rgb::new(150, 50, 1),
+			Rgb::new(0, 0, 0)
+		);
+	}
+
+	#[test]
+	fn test_mul() {
+		let rgb = Rgb::new(100, 150, 200);
+		assert_eq!(rgb * 0.5, Rgb::new(50, 75, 100));
+		assert_eq!(rgb * 1., rgb);
+		assert_eq!(rgb * 2., Rgb::new(200, 255, 255));
+		assert_eq!(rgb * -1., Rgb::new(0, 0, 0));
+	}
+
+	#[test]
+	fn test_to_web_safe() {
+		assert_eq!(
+			Rgb::new(200, 200, 200).to_web_safe(),
+			Rgb::new(204, 204, 204)
+		);
+		assert_eq!(Rgb::new(25, 26, 255).to_web_safe(), Rgb::new(0, 51, 255));
+		assert!(Rgb::new(30, 50, 60).to_web_safe().is_web_safe());
+	}
+
+	#[test]
+	fn test_is_web_safe() {
+		assert!(Rgb::new(0, 0, 0).is_web_safe());
+		assert!(Rgb::new(51, 153, 255).is_web_safe());
+		assert!(!Rgb::new(200, 200, 200).is_web_safe());
+	}
+
+	#[test]
+	fn test_bytes_round_trip() {
+		for color in [
+			Rgb::new(30, 50, 60),
+			Rgb::new(0, 0, 0),
+			Rgb::new(255, 255, 255),
+		]
+		.iter()
+		{
+			assert_eq!(Rgb::from_bytes(color.to_bytes()), *color);
+		}
+	}
+
+	#[test]
+	fn test_bytes_order() {
+		assert_eq!(Rgb::new(1, 2, 3).to_bytes(), [1, 2, 3]);
+	}
+
+	#[test]
+	fn test_ord() {
+		let mut colors = vec![
+			Rgb::new(0, 0, 255),
+			Rgb::new(255, 0, 0),
+			Rgb::new(0, 255, 0),
+			Rgb::new(0, 255, 1),
+		];
+		colors.sort();
+
+		assert_eq!(
+			colors,
+			vec![
+				Rgb::new(0, 0, 255),
+				Rgb::new(0, 255, 0),
+				Rgb::new(0, 255, 1),
+				Rgb::new(255, 0, 0),
+			]
+		);
+	}
+
+	#[test]
+	fn test_sort_by_luminance() {
+		let mut colors = vec![
+			Rgb::new(255, 255, 255),
+			Rgb::new(255, 0, 0),
+			Rgb::new(0, 0, 0),
+			Rgb::new(0, 255, 0),
+			Rgb::new(0, 0, 255),
+		];
+		colors.sort_by_key(|color| color.sort_key_by_luminance());
+
+		assert_eq!(
+			colors,
+			vec![
+				Rgb::new(0, 0, 0),
+				Rgb::new(0, 0, 255),
+				Rgb::new(255, 0, 0),
+				Rgb::new(0, 255, 0),
+				Rgb::new(255, 255, 255),
+			]
+		);
+	}
+
+	#[test]
+	fn test_distance() {
+		let black = Rgb::new(0, 0, 0);
+		let white = Rgb::new(255, 255, 255);
+		let rgb = Rgb::new(30, 50, 60);
+
+		assert_eq!(rgb.distance(rgb), 0.);
+		assert_eq!(Rgb::new(0, 0, 0).distance(Rgb::new(3, 4, 0)), 5.);
+		assert!((black.distance(white) - 441.673).abs() < 0.001);
+		assert_eq!(black.distance(rgb), rgb.distance(black));
+		assert_eq!(white.distance(rgb), rgb.distance(white));
+	}
+
+	#[test]
+	fn test_is_dark() {
+		let black = Rgb::new(0, 0, 0);
+		let white = Rgb::new(255, 255, 255);
+		let mid_gray = Rgb::new(128, 128, 128);
+		let yellow = Rgb::new(255, 255, 0);
+
+		assert!(black.is_dark());
+		assert!(!black.is_light());
+		assert!(mid_gray.is_light());
+		assert!(yellow.is_light());
+		assert!(white.is_light());
+		// Naively averaging the channels would call pure blue mid-bright
+		assert!(Rgb::new(0, 0, 255).is_dark());
+	}
+
+	#[test]
+	fn test_readable_text_color() {
+		let black = Rgb::new(0, 0, 0);
+		let white = Rgb::new(255, 255, 255);
+
+		assert_eq!(black.readable_text_color(), white);
+		assert_eq!(Rgb::new(128, 128, 128).readable_text_color(), black);
+		assert_eq!(Rgb::new(255, 255, 0).readable_text_color(), black);
+		assert_eq!(Rgb::new(100, 100, 100).readable_text_color(), white);
+	}
+
+	#[test]
+	fn test_to_css() {
+		assert_eq!(
+			Rgb::new(0, 255, 255).to_css(),
+			String::from("rgb(0, 255, 255)")
+		);
+	}
+
+	#[test]
+	fn test_with_channels() {
+		let rgb = Rgb::new(30, 50, 60);
+
+		assert_eq!(rgb.with_red(0), Rgb::new(0, 50, 60));
+		assert_eq!(rgb.with_green(0), Rgb::new(30, 0, 60));
+		assert_eq!(rgb.with_blue(0), Rgb::new(30, 50, 0));
+		assert_eq!(
+			rgb.with_red(1).with_green(2).with_blue(3),
+			Rgb::new(1, 2, 3)
+		);
+	}
+
+	#[test]
+	fn test_nearest_in() {
+		let palette = [
+			Rgb::new(0, 0, 0),
+			Rgb::new(255, 0, 0),
+			Rgb::new(0, 255, 0),
+			Rgb::new(0, 0, 255),
+			Rgb::new(255, 255, 255),
+		];
+
+		assert_eq!(
+			Rgb::new(200, 30, 40).nearest_in(&palette),
+			Rgb::new(255, 0, 0)
+		);
+		assert_eq!(
+			Rgb::new(10, 180, 60).nearest_in(&palette),
+			Rgb::new(0, 255, 0)
+		);
+		assert_eq!(Rgb::new(20, 20, 30).nearest_in(&palette), Rgb::new(0, 0, 0));
+		assert_eq!(
+			Rgb::new(230, 240, 220).nearest_in(&palette),
+			Rgb::new(255, 255, 255)
+		);
+		assert_eq!(
+			Rgb::new(0, 0, 255).nearest_in(&palette),
+			Rgb::new(0, 0, 255)
+		);
+	}
+
+	#[test]
+	fn test_nearest_in_empty_palette() {
+		let rgb = Rgb::new(12, 34, 56);
+		assert_eq!(rgb.nearest_in(&[]), rgb);
+	}
+
+	#[test]
+	fn test_linear_round_trip() {
+		for red in 0..=255 {
+			let rgb = Rgb::new(red, 255 - red, red / 2);
+			assert_eq!(Rgb::from_linear(rgb.to_linear()), rgb);
+		}
+	}
+
+	#[test]
+	fn test_to_linear_toe() {
+		// 10 / 255 sits below the 0.04045 threshold, 11 / 255 just above it
+		let [below, above, _] = Rgb::new(10, 11, 0).to_linear();
+		assert!((below - 10. / 255. / 12.92).abs() < 1e-12);
+		assert!((above - ((11_f64 / 255. + 0.055) / 1.055).powf(2.4)).abs() < 1e-12);
+
+		assert_eq!(Rgb::new(0, 0, 0).to_linear(), [0., 0., 0.]);
+		assert_eq!(
+			Rgb::from_linear([0.0031308 / 2., 0., 0.]),
+			Rgb::new(5, 0, 0)
+		);
+	}
+
+	#[test]
+	fn test_gamma_round_trip() {
+		let rgb = Rgb::new(128, 200, 255);
+		assert_eq!(rgb.gamma_encode(1.), rgb);
+		assert_eq!(rgb.gamma_decode(2.2).gamma_encode(2.2), rgb);
+		assert_eq!(Rgb::new(0, 0, 0).gamma_encode(2.2), Rgb::new(0, 0, 0));
+		assert_eq!(
+			Rgb::new(255, 255, 255).gamma_decode(2.2),
+			Rgb::new(255, 255, 255)
+		);
+	}
+
+	#[test]
+	fn test_roundtrip_tolerances() {
+		use crate::testing::{self, assert_roundtrip_within};
+
+		for red in (0..=255).step_by(5) {
+			for green in (0..=255).step_by(5) {
+				for blue in (0..=255).step_by(5) {
+					let rgb = Rgb::new(red, green, blue);
+
+					assert_roundtrip_within::<Cmyk>(rgb, testing::CMYK_TOLERANCE);
+					assert_roundtrip_within::<Hsv>(rgb, testing::HSV_TOLERANCE);
+
+					assert_roundtrip_within::<Hsl>(rgb, testing::HSL_TOLERANCE);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_tint_shade_tone() {
+		let color = Rgb::new(30, 50, 200);
+
+		assert_eq!(color.tint(0.), color);
+		assert_eq!(color.shade(0.), color);
+		assert_eq!(color.tone(0.), color);
+
+		assert_eq!(color.tint(1.), Rgb::new(255, 255, 255));
+		assert_eq!(color.shade(1.), Rgb::new(0, 0, 0));
+		assert_eq!(color.tone(1.), Rgb::new(128, 128, 128));
+
+		assert_eq!(color.tint(0.2), Rgb::new(75, 91, 211));
+		assert_eq!(color.shade(2.), Rgb::new(0, 0, 0));
+	}
+
+	#[test]
+	fn test_to_hex_string_short() {
+		assert_eq!(
+			Rgb::from_u32(0x00ffff).to_hex_string_short(),
+			Some(String::from("#0ff"))
+		);
+		assert_eq!(
+			Rgb::from_u32(0xaabbcc).to_hex_string_short(),
+			Some(String::from("#abc"))
+		);
+		assert_eq!(Rgb::from_u32(0x1e323c).to_hex_string_short(), None);
+		assert_eq!(Rgb::from_u32(0x00fff0).to_hex_string_short(), None);
+	}
+
+	#[test]
+	fn test_describe() {
+		assert_eq!(
+			Rgb::new(30, 50, 60).describe(),
+			String::from("Rgb { r: 30 (0x1e), g: 50 (0x32), b: 60 (0x3c) }")
+		);
+		assert_eq!(
+			Rgb::new(0, 5, 255).describe(),
+			String::from("Rgb { r: 0 (0x00), g: 5 (0x05), b: 255 (0xff) }")
+		);
+	}
+
+	#[test]
+	fn test_to_hex_string_short_parses_back() {
+		let rgb = Rgb::new(0x11, 0x22, 0x33);
+		let short = rgb.to_hex_string_short().unwrap();
+		assert_eq!(Rgb::from_hex_str(&short).unwrap(), rgb);
+	}
+
+	#[test]
+	fn test_dominant_channel() {
+		assert_eq!(Rgb::new(200, 30, 40).dominant_channel(), Channel::Red);
+		assert_eq!(Rgb::new(10, 180, 60).dominant_channel(), Channel::Green);
+		assert_eq!(Rgb::new(20, 20, 30).dominant_channel(), Channel::Blue);
+	}
+
+	#[test]
+	fn test_dominant_channel_ties() {
+		assert_eq!(Rgb::new(0, 0, 0).dominant_channel(), Channel::Red);
+		assert_eq!(Rgb::new(255, 255, 0).dominant_channel(), Channel::Red);
+		assert_eq!(Rgb::new(255, 0, 255).dominant_channel(), Channel::Red);
+		assert_eq!(Rgb::new(0, 255, 255).dominant_channel(), Channel::Green);
+	}
+
+	#[test]
+	fn test_channel_extrema() {
+		let rgb = Rgb::new(200, 30, 90);
+		assert_eq!(rgb.max_channel(), 200);
+		assert_eq!(rgb.min_channel(), 30);
+		assert_eq!(rgb.chroma(), 170);
+
+		assert_eq!(Rgb::new(0, 0, 0).chroma(), 0);
+		assert_eq!(Rgb::new(255, 0, 0).chroma(), 255);
+		assert_eq!(Rgb::new(77, 77, 77).max_channel(), 77);
+		assert_eq!(Rgb::new(77, 77, 77).min_channel(), 77);
+	}
+
+	#[test]
+	fn test_from_f64() {
+		assert_eq!(Rgb::from_f64(0., 0., 0.), Rgb::new(0, 0, 0));
+		assert_eq!(Rgb::from_f64(0.5, 0.5, 0.5), Rgb::new(128, 128, 128));
+		assert_eq!(Rgb::from_f64(1., 1., 1.), Rgb::new(255, 255, 255));
+	}
+
+	#[test]
+	fn test_from_f64_clamps() {
+		assert_eq!(Rgb::from_f64(-0.5, 1.5, 0.), Rgb::new(0, 255, 0));
+		assert_eq!(
+			Rgb::from_f64(f64::NEG_INFINITY, f64::INFINITY, 1.),
+			Rgb::new(0, 255, 255)
+		);
+	}
+
+	#[test]
+	fn test_f64_round_trip() {
+		for channel in 0..=255 {
+			let rgb = Rgb::new(channel, 255 - channel, channel / 3);
+			let (red, green, blue) = rgb.to_f64();
+			assert_eq!(Rgb::from_f64(red, green, blue), rgb);
+		}
+		assert_eq!(Rgb::new(0, 0, 255).to_f64(), (0., 0., 1.));
+	}
+
+	#[test]
+	fn test_desaturate_preserving_luminance() {
+		for rgb in [
+			Rgb::new(255, 0, 0),
+			Rgb::new(30, 50, 200),
+			Rgb::new(0, 255, 255),
+			Rgb::new(204, 153, 102),
+		]
+		.iter()
+		{
+			let luminance = rgb.relative_luminance();
+			for &amount in [0., 0.25, 0.5, 0.75, 1.].iter() {
+				let desaturated = rgb.desaturate_preserving_luminance(amount);
+				assert!((desaturated.relative_luminance() - luminance).abs() < 0.005);
+			}
+
+			assert_eq!(rgb.desaturate_preserving_luminance(0.), *rgb);
+
+			let gray = rgb.desaturate_preserving_luminance(1.);
+			assert!(gray.red == gray.green && gray.green == gray.blue);
+		}
+	}
+
+	#[test]
+	fn test_to_hsl_saturation() {
+		// The saturation denominator is `1 - |2L - 1|` on both sides of 50%
+		// lightness
+		assert_eq!(
+			Rgb::new(230, 200, 200).to_hsl(),
+			Hsl::new_unchecked(0, 38, 84)
+		);
+		assert_eq!(
+			Rgb::new(0, 0, 135).to_hsl(),
+			Hsl::new_unchecked(240, 100, 26)
+		);
+		assert_eq!(Rgb::new(60, 20, 20).to_hsl(), Hsl::new_unchecked(0, 50, 16));
+	}
+
+	#[test]
+	fn test_slice_conversions() {
+		let colors = [
+			Rgb::new(30, 50, 60),
+			Rgb::new(0, 255, 255),
+			Rgb::new(204, 153, 102),
+			Rgb::new(0, 0, 0),
+		];
+
+		let hsl: Vec<Hsl> = colors.iter().map(|rgb| rgb.to_hsl()).collect();
+		let hsv: Vec<Hsv> = colors.iter().map(|rgb| rgb.to_hsv()).collect();
+		let cmyk: Vec<Cmyk> = colors.iter().map(|rgb| rgb.to_cmyk()).collect();
+
+		assert_eq!(Rgb::slice_to_hsl(&colors), hsl);
+		assert_eq!(Rgb::slice_to_hsv(&colors), hsv);
+		assert_eq!(Rgb::slice_to_cmyk(&colors), cmyk);
+		assert!(Rgb::slice_to_hsl(&[]).is_empty());
+
+		let mut output = [Hsl::default(); 4];
+		Rgb::slice_to_hsl_into(&colors, &mut output);
+		assert_eq!(output[..], hsl[..]);
+	}
+
+	#[should_panic]
+	#[test]
+	fn test_slice_to_hsl_into_length_mismatch() {
+		let mut output = [Hsl::default(); 1];
+		Rgb::slice_to_hsl_into(&[Rgb::new(0, 0, 0), Rgb::new(1, 1, 1)], &mut output);
+	}
+
+	#[test]
+	fn test_display_alternate() {
+		let rgb = Rgb::new(30, 50, 60);
+
+		assert_eq!(format!("{}", rgb), String::from("rgb(30, 50, 60)"));
+		assert_eq!(format!("{:#}", rgb), String::from("#1e323c"));
+		assert_eq!(format!("{:#}", Rgb::new(0, 0, 0)), String::from("#000000"));
+		assert_eq!(format!("{:#}", rgb), rgb.to_hex_string());
+	}
+
+	#[test]
+	fn test_quantize() {
+		assert_eq!(Rgb::new(127, 128, 255).quantize(1), Rgb::new(0, 255, 255));
+		assert_eq!(
+			Rgb::new(10, 200, 90).quantize(0),
+			Rgb::new(10, 200, 90).quantize(1)
+		);
+
+		let levels = [0, 36, 73, 109, 146, 182, 219, 255];
+		for channel in 0..=255 {
+			let quantized = Rgb::new(channel, 0, 0).quantize(3).red;
+			assert!(levels.contains(&quantized));
+			assert!((quantized as i32 - channel as i32).abs() <= 18);
+		}
+		assert_eq!(Rgb::new(30, 50, 200).quantize(3), Rgb::new(36, 36, 182));
+
+		for channel in 0..=255 {
+			let rgb = Rgb::new(channel, 255 - channel, channel / 2);
+			assert_eq!(rgb.quantize(8), rgb);
+			assert_eq!(rgb.quantize(12), rgb);
+		}
+	}
+
+	#[test]
+	fn test_average() {
+		let red = Rgb::new(255, 0, 0);
+		let blue = Rgb::new(0, 0, 255);
+
+		assert_eq!(
+			Rgb::average(&[(red, 1.), (blue, 1.)]),
+			Rgb::new(188, 0, 188)
+		);
+		assert_eq!(
+			Rgb::average(&[(red, 0.2), (blue, 0.2)]),
+			Rgb::new(188, 0, 188)
+		);
+		assert_eq!(Rgb::average(&[(red, 3.)]), red);
+		assert_eq!(Rgb::average(&[(red, 1.), (blue, 0.)]), red);
+	}
+
+	#[test]
+	fn test_average_weighted() {
+		let white = Rgb::new(255, 255, 255);
+		let black = Rgb::new(0, 0, 0);
+		let green = Rgb::new(0, 255, 0);
+
+		// Weights of 2:1:1 give half white, a quarter black, and a quarter
+		// green in linear light
+		let average = Rgb::average(&[(white, 2.), (black, 1.), (green, 1.)]);
+		assert_eq!(average, Rgb::from_linear([0.5, 0.75, 0.5]));
+		assert_eq!(average, Rgb::new(188, 225, 188));
+	}
+
+	#[test]
+	fn test_average_empty() {
+		assert_eq!(Rgb::average(&[]), Rgb::new(0, 0, 0));
+		assert_eq!(
+			Rgb::average(&[(Rgb::new(255, 255, 255), 0.)]),
+			Rgb::new(0, 0, 0)
+		);
+	}
+
+	#[test]
+	fn test_adjust_for_contrast() {
+		let white = Rgb::new(255, 255, 255);
+		let black = Rgb::new(0, 0, 0);
+
+		for &(color, background) in [
+			(Rgb::new(120, 170, 255), white),
+			(Rgb::new(255, 200, 0), white),
+			(Rgb::new(30, 50, 60), black),
+			(Rgb::new(128, 128, 128), Rgb::new(100, 100, 100)),
+		]
+		.iter()
+		{
+			let adjusted = color.adjust_for_contrast(background, 4.5);
+			assert!(adjusted.contrast_ratio(background) >= 4.5, "{:?}", color);
+			// The hue only drifts by rounding through whole-degree HSL
+			let hue_drift = adjusted.to_hsl().hue as i32 - color.to_hsl().hue as i32;
+			assert!(hue_drift.abs() <= 2, "{:?}", color);
+		}
+
+		let readable = Rgb::new(0, 0, 139);
+		assert_eq!(readable.adjust_for_contrast(white, 4.5), readable);
+	}
+
+	#[test]
+	fn test_adjust_for_contrast_unreachable() {
+		let gray = Rgb::new(128, 128, 128);
+
+		assert_eq!(
+			Rgb::new(200, 30, 30).adjust_for_contrast(gray, 10.),
+			Rgb::new(0, 0, 0)
+		);
+		assert_eq!(
+			Rgb::new(200, 30, 30).adjust_for_contrast(Rgb::new(20, 20, 20), 30.),
+			Rgb::new(255, 255, 255)
+		);
+	}
+
+	#[test]
+	fn test_to_color_mix_string() {
+		let red = Rgb::new(255, 0, 0);
+		let blue = Rgb::new(0, 0, 255);
+
+		assert_eq!(
+			red.to_color_mix_string(blue, 0.5, "srgb"),
+			String::from("color-mix(in srgb, #ff0000 50%, #0000ff)")
+		);
+		assert_eq!(
+			red.to_color_mix_string(blue, 0.25, "oklab"),
+			String::from("color-mix(in oklab, #ff0000 75%, #0000ff)")
+		);
+		assert_eq!(
+			red.to_color_mix_string(blue, 2. / 3., "oklab"),
+			String::from("color-mix(in oklab, #ff0000 33.33%, #0000ff)")
+		);
+		assert_eq!(
+			red.to_color_mix_string(blue, 1.5, "srgb"),
+			String::from("color-mix(in srgb, #ff0000 0%, #0000ff)")
+		);
+	}
+
+	#[test]
+	fn test_most_common() {
+		let red = Rgb::new(255, 0, 0);
+		let green = Rgb::new(0, 255, 0);
+		let blue = Rgb::new(0, 0, 255);
+
+		assert_eq!(
+			Rgb::most_common(&[blue, red, green, red, blue, red]),
+			Some(red)
+		);
+		assert_eq!(Rgb::most_common(&[green]), Some(green));
+		assert_eq!(Rgb::most_common(&[]), None);
+
+		// Ties go to the smallest color
+		assert_eq!(Rgb::most_common(&[red, blue, blue, red]), Some(blue));
+	}
+
+	#[test]
+	fn test_dominant_colors() {
+		let mut pixels = Vec::new();
+		for offset in 0..6 {
+			pixels.push(Rgb::new(20 + offset, 30, 200 - offset));
+		}
+		for offset in 0..4 {
+			pixels.push(Rgb::new(230 - offset, 220 + offset, 10));
+		}
+
+		let palette = Rgb::dominant_colors(&pixels, 2);
+		assert_eq!(palette.len(), 2);
+		assert!(palette[0]._distance(Rgb::new(22, 30, 197)) <= 3);
+		assert!(palette[1]._distance(Rgb::new(228, 221, 10)) <= 3);
+	}
+
+	#[test]
+	fn test_dominant_colors_more_clusters_than_groups() {
+		// Two natural groups, so one of the three seeds ends up with no pixels
+		let pixels = [
+			Rgb::new(0, 160, 160),
+			Rgb::new(240, 80, 160),
+			Rgb::new(240, 160, 160),
+			Rgb::new(240, 240, 160),
+			Rgb::new(0, 240, 80),
+		];
+
+		assert_eq!(
+			Rgb::dominant_colors(&pixels, 3),
+			vec![Rgb::new(240, 160, 160), Rgb::new(0, 200, 120)]
+		);
+	}
+
+	#[test]
+	fn test_dominant_colors_edge_cases() {
+		let red = Rgb::new(255, 0, 0);
+
+		assert!(Rgb::dominant_colors(&[], 3).is_empty());
+		assert!(Rgb::dominant_colors(&[red], 0).is_empty());
+		assert_eq!(Rgb::dominant_colors(&[red, red, red], 3), vec![red]);
+	}
+
+	#[test]
+	fn test_to_tuple() {
+		let rgb = Rgb::new(30, 50, 60);
+		let (red, green, blue) = rgb.to_tuple();
+		assert_eq!(Rgb::new(red, green, blue), rgb);
 	}
 }
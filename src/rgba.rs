@@ -0,0 +1,938 @@
+use crate::{css, parse, prelude::*};
+use core::{fmt, str::FromStr};
+
+///
+/// A representation of the RGBA (red, green, blue, alpha) color format.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rgba {
+	/// Red value
+	pub red: u8,
+	/// Green value
+	pub green: u8,
+	/// Blue value
+	pub blue: u8,
+	/// Alpha value, where 0 is fully transparent and 255 is fully opaque
+	pub alpha: u8,
+}
+
+impl Rgba {
+	///
+	/// Returns a new Rgba object given red, green, blue, and alpha values.
+	///
+	/// # Arguments
+	///
+	/// * `red` - the red value of the color
+	/// * `green` - the green value of the color
+	/// * `blue` - the blue value of the color
+	/// * `alpha` - the alpha value of the color
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgba;
+	/// let translucent_cyan = Rgba::new(0, 255, 255, 128);
+	/// // ...
+	/// ```
+	///
+	/// # Note
+	///
+	/// * `red`, `green`, `blue`, and `alpha` are all 8-bit integers with a
+	///   maximum value of 255.
+	///
+	pub const fn new(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
+		Self {
+			red,
+			green,
+			blue,
+			alpha,
+		}
+	}
+
+	///
+	/// Returns a new Rgba object given an [`Rgb`] color and an alpha value.
+	/// The alpha channel can be dropped again with
+	/// [`Color::to_rgb`](crate::Color::to_rgb).
+	///
+	/// # Arguments
+	///
+	/// * `rgb` - the color
+	/// * `alpha` - the alpha value of the color
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Color;
+	/// use color_conv::Rgb;
+	/// use color_conv::Rgba;
+	///
+	/// let cyan = Rgb::new(0, 255, 255);
+	/// let translucent_cyan = Rgba::from_rgb(cyan, 128);
+	///
+	/// assert_eq!(translucent_cyan, Rgba::new(0, 255, 255, 128));
+	/// assert_eq!(translucent_cyan.to_rgb(), cyan);
+	/// ```
+	///
+	pub fn from_rgb(rgb: Rgb, alpha: u8) -> Self {
+		Self::new(rgb.red, rgb.green, rgb.blue, alpha)
+	}
+
+	///
+	/// Returns a Result containing a new Rgba object given an [`Rgb`] color
+	/// and an alpha value normalized to the range `0.0` to `1.0`, as written
+	/// in CSS. Will return an [`Error::AlphaOverflow`] if the alpha is
+	/// outside of that range rather than clamping it.
+	///
+	/// # Arguments
+	///
+	/// * `rgb` - the color
+	/// * `alpha` - the normalized alpha value of the color
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::{Rgb, Rgba};
+	///
+	/// let translucent_cyan = Rgba::try_from_rgb(Rgb::new(0, 255, 255), 0.5)?;
+	/// assert_eq!(translucent_cyan, Rgba::new(0, 255, 255, 128));
+	///
+	/// assert!(Rgba::try_from_rgb(Rgb::new(0, 255, 255), 2.).is_err());
+	/// # Ok::<(), color_conv::Error>(())
+	/// ```
+	///
+	pub fn try_from_rgb(rgb: Rgb, alpha: f64) -> Result<Self, Error> {
+		if !(0. ..=1.).contains(&alpha) {
+			return Err(Error::AlphaOverflow);
+		}
+
+		Ok(Self::from_rgb(rgb, (alpha * 255.).round() as u8))
+	}
+
+	///
+	/// Returns a Result containing a new Rgba object parsed from a hex color
+	/// string. Accepts the `#rrggbbaa` and shorthand `#rgba` forms, with or
+	/// without the leading hashtag (`#`), and round-trips with
+	/// [`Color::to_hex_string`]. Will return an [`Error::InvalidHex`] if the
+	/// string contains non-hexadecimal digits or has the wrong length.
+	///
+	/// # Arguments
+	///
+	/// * `hex` - the hex string to parse
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgba;
+	///
+	/// let rgba = Rgba::from_hex_str("#1e323c80")?;
+	/// assert_eq!(rgba, Rgba::new(30, 50, 60, 128));
+	///
+	/// let short = Rgba::from_hex_str("0ff8")?;
+	/// assert_eq!(short, Rgba::new(0, 255, 255, 136));
+	/// # Ok::<(), color_conv::Error>(())
+	/// ```
+	///
+	pub fn from_hex_str(hex: &str) -> Result<Self, Error> {
+		let channels = parse::hex_channels(hex, 4)?;
+		Ok(Self::new(
+			channels[0],
+			channels[1],
+			channels[2],
+			channels[3],
+		))
+	}
+
+	///
+	/// Returns a new Rgba object unpacked from a `u32` laid out as
+	/// `0xRRGGBBAA`.
+	///
+	/// # Arguments
+	///
+	/// * `packed` - the packed color value
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgba;
+	///
+	/// let rgba = Rgba::from_u32(0x1e323c80);
+	/// assert_eq!(rgba, Rgba::new(0x1e, 0x32, 0x3c, 0x80));
+	/// ```
+	///
+	pub const fn from_u32(packed: u32) -> Self {
+		Self::new(
+			(packed >> 24) as u8,
+			(packed >> 16) as u8,
+			(packed >> 8) as u8,
+			packed as u8,
+		)
+	}
+
+	///
+	/// Converts to a CSS `rgba(...)` string like
+	/// [`Color::to_css`](crate::Color::to_css), but with the alpha value
+	/// normalized to `0.0`-`1.0` and always written to exactly `decimals`
+	/// decimal places rather than rounded to at most two.
+	///
+	/// # Arguments
+	///
+	/// * `decimals` - the number of decimal places to write the alpha with
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgba;
+	///
+	/// let rgba = Rgba::new(0, 255, 255, 85);
+	/// assert_eq!(rgba.to_css_precise(3), String::from("rgba(0, 255, 255, 0.333)"));
+	/// ```
+	///
+	pub fn to_css_precise(self, decimals: usize) -> String {
+		let alpha = self.alpha as f64 / 255.;
+		format!(
+			"rgba({}, {}, {}, {:.*})",
+			self.red, self.green, self.blue, decimals, alpha
+		)
+	}
+
+	///
+	/// Packs the color into a `u32` laid out as `0xRRGGBBAA`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgba;
+	///
+	/// let packed = Rgba::new(0x1e, 0x32, 0x3c, 0x80).to_u32();
+	/// assert_eq!(packed, 0x1e323c80);
+	/// ```
+	///
+	pub fn to_u32(self) -> u32 {
+		(self.red as u32) << 24
+			| (self.green as u32) << 16
+			| (self.blue as u32) << 8
+			| self.alpha as u32
+	}
+
+	///
+	/// Returns a new Rgba object unpacked from a `u32` laid out as
+	/// `0xAARRGGBB`, the alpha-first ordering used by many graphics APIs.
+	/// See [`Rgba::from_u32`] for the `0xRRGGBBAA` ordering.
+	///
+	/// # Arguments
+	///
+	/// * `packed` - the packed color value
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgba;
+	///
+	/// let rgba = Rgba::from_argb(0x801e323c);
+	/// assert_eq!(rgba, Rgba::new(0x1e, 0x32, 0x3c, 0x80));
+	/// ```
+	///
+	pub const fn from_argb(packed: u32) -> Self {
+		Self::new(
+			(packed >> 16) as u8,
+			(packed >> 8) as u8,
+			packed as u8,
+			(packed >> 24) as u8,
+		)
+	}
+
+	///
+	/// Packs the color into a `u32` laid out as `0xAARRGGBB`. See
+	/// [`Rgba::from_argb`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgba;
+	///
+	/// let packed = Rgba::new(0x1e, 0x32, 0x3c, 0x80).to_argb();
+	/// assert_eq!(packed, 0x801e323c);
+	/// ```
+	///
+	pub fn to_argb(self) -> u32 {
+		(self.alpha as u32) << 24
+			| (self.red as u32) << 16
+			| (self.green as u32) << 8
+			| self.blue as u32
+	}
+
+	///
+	/// Creates a new Rgba object from bytes ordered R, G, B, A, as found in
+	/// tightly packed image buffers.
+	///
+	/// # Arguments
+	///
+	/// * `bytes` - the red, green, blue, and alpha channels, in that order
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgba;
+	///
+	/// let color = Rgba::from_bytes([30, 50, 60, 128]);
+	/// assert_eq!(color, Rgba::new(30, 50, 60, 128));
+	/// ```
+	///
+	pub fn from_bytes(bytes: [u8; 4]) -> Self {
+		let [red, green, blue, alpha] = bytes;
+		Self::new(red, green, blue, alpha)
+	}
+
+	///
+	/// Returns the channels of the color as bytes ordered R, G, B, A. See
+	/// [`Rgba::from_bytes`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgba;
+	///
+	/// let bytes = Rgba::new(30, 50, 60, 128).to_bytes();
+	/// assert_eq!(bytes, [30, 50, 60, 128]);
+	/// ```
+	///
+	pub fn to_bytes(self) -> [u8; 4] {
+		[self.red, self.green, self.blue, self.alpha]
+	}
+
+	///
+	/// Returns the color with its red, green, and blue channels multiplied by
+	/// its alpha, as used when compositing. Each channel is rounded to the
+	/// nearest integer, so a color premultiplied by a low alpha loses
+	/// precision that [`Rgba::unpremultiply`] cannot recover.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgba;
+	///
+	/// let premultiplied = Rgba::new(255, 128, 0, 128).premultiply();
+	/// assert_eq!(premultiplied, Rgba::new(128, 64, 0, 128));
+	/// ```
+	///
+	pub fn premultiply(self) -> Rgba {
+		let alpha = self.alpha as u16;
+		let apply = |channel: u8| ((channel as u16 * alpha + 127) / 255) as u8;
+
+		Rgba::new(
+			apply(self.red),
+			apply(self.green),
+			apply(self.blue),
+			self.alpha,
+		)
+	}
+
+	///
+	/// Reverses [`Rgba::premultiply`] by dividing the red, green, and blue
+	/// channels by the alpha. Each channel is rounded to the nearest integer
+	/// and capped at 255. A fully transparent color has no recoverable
+	/// channels, so an alpha of 0 gives `Rgba::new(0, 0, 0, 0)`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgba;
+	///
+	/// let straight = Rgba::new(128, 64, 0, 128).unpremultiply();
+	/// assert_eq!(straight, Rgba::new(255, 128, 0, 128));
+	/// ```
+	///
+	pub fn unpremultiply(self) -> Rgba {
+		if self.alpha == 0 {
+			return Rgba::new(0, 0, 0, 0);
+		}
+
+		let alpha = self.alpha as u16;
+		let apply = |channel: u8| ((channel as u16 * 255 + alpha / 2) / alpha).min(255) as u8;
+
+		Rgba::new(
+			apply(self.red),
+			apply(self.green),
+			apply(self.blue),
+			self.alpha,
+		)
+	}
+
+	///
+	/// Composites `self` over `background` using the Porter-Duff source-over
+	/// operator, as when layering a semi-transparent color on top of another.
+	/// Compositing is done on premultiplied channels, and the result is
+	/// converted back to straight alpha and rounded.
+	///
+	/// # Arguments
+	///
+	/// * `background` - the color underneath `self`
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgba;
+	///
+	/// let translucent_red = Rgba::new(255, 0, 0, 128);
+	/// let translucent_blue = Rgba::new(0, 0, 255, 128);
+	///
+	/// assert_eq!(
+	///     translucent_red.over(translucent_blue),
+	///     Rgba::new(170, 0, 85, 192)
+	/// );
+	/// ```
+	///
+	pub fn over(self, background: Rgba) -> Rgba {
+		let source_alpha = self.alpha as f64 / 255.;
+		let background_alpha = background.alpha as f64 / 255. * (1. - source_alpha);
+		let alpha = source_alpha + background_alpha;
+
+		if alpha <= 0. {
+			return Rgba::new(0, 0, 0, 0);
+		}
+
+		let apply = |source: u8, background: u8| {
+			((source as f64 * source_alpha + background as f64 * background_alpha) / alpha).round()
+				as u8
+		};
+
+		Rgba::new(
+			apply(self.red, background.red),
+			apply(self.green, background.green),
+			apply(self.blue, background.blue),
+			(alpha * 255.).round() as u8,
+		)
+	}
+
+	///
+	/// Flattens `self` onto an opaque background, giving the color that
+	/// would be seen on screen. See [`Rgba::over`].
+	///
+	/// # Arguments
+	///
+	/// * `background` - the opaque color underneath `self`
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::{Rgb, Rgba};
+	///
+	/// let translucent_red = Rgba::new(255, 0, 0, 128);
+	/// let white = Rgb::new(255, 255, 255);
+	///
+	/// assert_eq!(translucent_red.composite_on(white), Rgb::new(255, 127, 127));
+	/// ```
+	///
+	pub fn composite_on(self, background: Rgb) -> Rgb {
+		self.over(Rgba::from_rgb(background, 255)).to_rgb()
+	}
+}
+
+impl fmt::Display for Rgba {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		// Alpha is normalized to 0.0-1.0 and written with the fewest decimal
+		// places (up to three) that still parse back to the same byte
+		let normalized = self.alpha as f64 / 255.;
+		let alpha = [10., 100., 1000.]
+			.iter()
+			.map(|scale| (normalized * scale).round() / scale)
+			.find(|alpha| (alpha * 255.).round() as u8 == self.alpha)
+			.unwrap_or(normalized);
+
+		write!(
+			f,
+			"rgba({}, {}, {}, {})",
+			self.red,
+			self.green,
+			self.blue,
+			css::alpha(alpha)
+		)
+	}
+}
+
+impl FromStr for Rgba {
+	type Err = Error;
+
+	///
+	/// Parses a string of the form `rgba(r, g, b, a)`, as produced by the
+	/// [`Display`](core::fmt::Display) implementation of [`Rgba`], or
+	/// `rgb(r, g, b)`, in which case the color is fully opaque. The alpha is
+	/// normalized to `0.0`-`1.0`, or a percentage, and must lie within that
+	/// range (see [`Rgba::try_from_rgb`]). As with [`Rgb`], the color
+	/// channels are either all integers or all percentages. Whitespace around
+	/// each value is ignored.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Rgba;
+	///
+	/// let translucent_cyan = "rgba(0, 255, 255, 0.5)".parse::<Rgba>()?;
+	/// assert_eq!(translucent_cyan, Rgba::new(0, 255, 255, 128));
+	///
+	/// let cyan = "rgb(0, 255, 255)".parse::<Rgba>()?;
+	/// assert_eq!(cyan, Rgba::new(0, 255, 255, 255));
+	/// # Ok::<(), color_conv::Error>(())
+	/// ```
+	///
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let args = if s.trim_start().starts_with("rgba") {
+			parse::function_args(s, "rgba")?
+		} else {
+			parse::function_args(s, "rgb")?
+		};
+
+		match args[..] {
+			[_, _, _] => {
+				let channels = parse::rgb_channels(&args)?;
+				Ok(Rgba::new(channels[0], channels[1], channels[2], 255))
+			}
+			[red, green, blue, alpha] => {
+				let channels = parse::rgb_channels(&[red, green, blue])?;
+				Rgba::try_from_rgb(
+					Rgb::new(channels[0], channels[1], channels[2]),
+					parse::alpha(alpha)?,
+				)
+			}
+			_ => Err(Error::ParseError(format!(
+				"expected 3 or 4 values, got {}",
+				args.len()
+			))),
+		}
+	}
+}
+
+///
+/// Unpacks a `u32` laid out as `0xRRGGBBAA`, like [`Rgba::from_u32`]. Since
+/// every `u32` is a valid color, this also provides an infallible
+/// `TryFrom<u32>`. Use [`Rgba::from_argb`] for `0xAARRGGBB` values.
+///
+impl From<u32> for Rgba {
+	fn from(packed: u32) -> Self {
+		Self::from_u32(packed)
+	}
+}
+
+impl Color for Rgba {
+	fn to_rgb(self) -> Rgb {
+		Rgb::new(self.red, self.green, self.blue)
+	}
+
+	fn to_cmyk(self) -> Cmyk {
+		self.to_rgb().to_cmyk()
+	}
+
+	fn to_hsl(self) -> Hsl {
+		self.to_rgb().to_hsl()
+	}
+
+	fn to_hsv(self) -> Hsv {
+		self.to_rgb().to_hsv()
+	}
+
+	fn to_hex_string(self) -> String {
+		format!(
+			"#{:0>2x}{:0>2x}{:0>2x}{:0>2x}",
+			self.red, self.green, self.blue, self.alpha
+		)
+	}
+
+	fn to_xyz(self) -> Xyz {
+		self.to_rgb().to_xyz()
+	}
+
+	fn to_lab(self) -> Lab {
+		self.to_rgb().to_lab()
+	}
+
+	fn to_hwb(self) -> Hwb {
+		self.to_rgb().to_hwb()
+	}
+
+	fn to_css(self) -> String {
+		self.to_string()
+	}
+}
+
+impl Invert for Rgba {
+	fn invert(self) -> Rgba {
+		Rgba::from_rgb(self.to_rgb().invert(), self.alpha)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::testing;
+
+	#[test]
+	fn test_to_string() {
+		let rgba = Rgba::new(0, 255, 255, 255);
+		assert_eq!(rgba.to_string(), String::from("rgba(0, 255, 255, 1)"));
+	}
+
+	#[test]
+	fn test_to_string_alpha_precision() {
+		assert_eq!(
+			Rgba::new(0, 0, 0, 0).to_string(),
+			String::from("rgba(0, 0, 0, 0)")
+		);
+		assert_eq!(
+			Rgba::new(0, 0, 0, 128).to_string(),
+			String::from("rgba(0, 0, 0, 0.5)")
+		);
+		assert_eq!(
+			Rgba::new(0, 0, 0, 85).to_string(),
+			String::from("rgba(0, 0, 0, 0.333)")
+		);
+	}
+
+	#[test]
+	fn test_to_hex_string() {
+		let hex = Rgba::new(0, 255, 255, 255).to_hex_string();
+		assert_eq!(hex, String::from("#00ffffff"));
+
+		let hex = Rgba::new(30, 50, 60, 8).to_hex_string();
+		assert_eq!(hex, String::from("#1e323c08"));
+	}
+
+	#[test]
+	fn test_from_hex_str() {
+		let rgba = Rgba::from_hex_str("#1e323c08").unwrap();
+		assert_eq!(rgba, Rgba::new(30, 50, 60, 8));
+		assert_eq!(Rgba::from_hex_str(&rgba.to_hex_string()).unwrap(), rgba);
+
+		let rgba = Rgba::from_hex_str("1E323C08").unwrap();
+		assert_eq!(rgba, Rgba::new(30, 50, 60, 8));
+	}
+
+	#[test]
+	fn test_from_hex_str_shorthand() {
+		let rgba = Rgba::from_hex_str("#0f8c").unwrap();
+		assert_eq!(rgba, Rgba::new(0x00, 0xff, 0x88, 0xcc));
+		assert_eq!(Rgba::from_hex_str("0f8c").unwrap(), rgba);
+	}
+
+	#[test]
+	fn test_from_hex_str_invalid() {
+		for hex in ["#1e323c0", "#1e323c", "#0f8", "#1e323c08f", "#1e323g08", ""].iter() {
+			assert!(
+				matches!(Rgba::from_hex_str(hex), Err(Error::InvalidHex)),
+				"{}",
+				hex
+			);
+		}
+	}
+
+	#[test]
+	fn test_to_rgb() {
+		let rgb = Rgba::new(30, 50, 60, 128).to_rgb();
+		assert_eq!(rgb, Rgb::new(30, 50, 60));
+	}
+
+	#[test]
+	fn test_from_rgb() {
+		let rgba = Rgba::from_rgb(Rgb::new(30, 50, 60), 128);
+		assert_eq!(rgba, Rgba::new(30, 50, 60, 128));
+	}
+
+	#[test]
+	fn test_to_cmyk() {
+		let cmyk = Rgba::new(30, 50, 60, 0).to_cmyk();
+		assert_eq!(cmyk, Cmyk::new_unchecked(50, 17, 0, 76));
+	}
+
+	#[test]
+	fn test_invert() {
+		let inverted = Rgba::new(30, 50, 60, 128).invert();
+		assert_eq!(inverted, Rgba::new(225, 205, 195, 128));
+	}
+
+	#[test]
+	fn test_u32_round_trip() {
+		for rgba in [
+			Rgba::new(30, 50, 60, 128),
+			Rgba::new(0, 0, 0, 0),
+			Rgba::new(255, 255, 255, 255),
+		]
+		.iter()
+		{
+			assert_eq!(Rgba::from_u32(rgba.to_u32()), *rgba);
+		}
+	}
+
+	#[test]
+	fn test_u32_byte_order() {
+		assert_eq!(Rgba::new(0x12, 0x34, 0x56, 0x78).to_u32(), 0x12345678);
+		assert_eq!(
+			Rgba::from_u32(0x12345678),
+			Rgba::new(0x12, 0x34, 0x56, 0x78)
+		);
+	}
+
+	#[test]
+	fn test_bytes_round_trip() {
+		for color in [
+			Rgba::new(30, 50, 60, 128),
+			Rgba::new(0, 0, 0, 0),
+			Rgba::new(255, 255, 255, 255),
+		]
+		.iter()
+		{
+			assert_eq!(Rgba::from_bytes(color.to_bytes()), *color);
+		}
+	}
+
+	#[test]
+	fn test_bytes_order() {
+		assert_eq!(Rgba::new(1, 2, 3, 4).to_bytes(), [1, 2, 3, 4]);
+	}
+
+	#[test]
+	fn test_premultiply() {
+		let rgba = Rgba::new(255, 128, 30, 255);
+		assert_eq!(rgba.premultiply(), rgba);
+		assert_eq!(
+			Rgba::new(255, 128, 30, 128).premultiply(),
+			Rgba::new(128, 64, 15, 128)
+		);
+		assert_eq!(
+			Rgba::new(255, 128, 30, 0).premultiply(),
+			Rgba::new(0, 0, 0, 0)
+		);
+	}
+
+	#[test]
+	fn test_unpremultiply() {
+		let rgba = Rgba::new(255, 128, 30, 255);
+		assert_eq!(rgba.unpremultiply(), rgba);
+		assert_eq!(
+			Rgba::new(128, 64, 15, 128).unpremultiply(),
+			Rgba::new(255, 128, 30, 128)
+		);
+		assert_eq!(
+			Rgba::new(10, 20, 30, 0).unpremultiply(),
+			Rgba::new(0, 0, 0, 0)
+		);
+		assert_eq!(
+			Rgba::new(200, 0, 0, 100).unpremultiply(),
+			Rgba::new(255, 0, 0, 100)
+		);
+	}
+
+	#[test]
+	fn test_premultiply_round_trip() {
+		for &alpha in [255, 128, 64].iter() {
+			for channel in 0..=255 {
+				let rgba = Rgba::new(channel, 255 - channel, channel / 2, alpha);
+				let back = rgba.premultiply().unpremultiply();
+				let tolerance = 255 / alpha;
+
+				assert!(testing::max_channel_error(rgba.to_rgb(), back.to_rgb()) <= tolerance);
+				assert_eq!(back.alpha, alpha);
+			}
+		}
+	}
+
+	#[test]
+	fn test_over() {
+		let red = Rgba::new(255, 0, 0, 128);
+		let white = Rgba::new(255, 255, 255, 255);
+		assert_eq!(red.over(white), Rgba::new(255, 127, 127, 255));
+
+		let opaque = Rgba::new(30, 50, 60, 255);
+		assert_eq!(opaque.over(white), opaque);
+
+		let transparent = Rgba::new(30, 50, 60, 0);
+		assert_eq!(transparent.over(red), red);
+		assert_eq!(transparent.over(transparent), Rgba::new(0, 0, 0, 0));
+	}
+
+	#[test]
+	fn test_composite_on() {
+		let white = Rgb::new(255, 255, 255);
+		let background = Rgb::new(30, 50, 60);
+
+		assert_eq!(
+			Rgba::new(255, 0, 0, 128).composite_on(white),
+			Rgb::new(255, 127, 127)
+		);
+		assert_eq!(Rgba::new(255, 0, 0, 0).composite_on(background), background);
+		assert_eq!(
+			Rgba::new(255, 0, 0, 255).composite_on(background),
+			Rgb::new(255, 0, 0)
+		);
+	}
+
+	#[test]
+	fn test_to_css() {
+		let rgba = Rgba::new(0, 255, 255, 128);
+		assert_eq!(rgba.to_css(), String::from("rgba(0, 255, 255, 0.5)"));
+	}
+
+	#[test]
+	fn test_from_u32_layouts() {
+		let rgba: Rgba = 0x11223344.into();
+		assert_eq!(
+			(rgba.red, rgba.green, rgba.blue, rgba.alpha),
+			(0x11, 0x22, 0x33, 0x44)
+		);
+		assert_eq!(Rgba::from_u32(0x11223344), rgba);
+
+		let argb = Rgba::from_argb(0x11223344);
+		assert_eq!(
+			(argb.red, argb.green, argb.blue, argb.alpha),
+			(0x22, 0x33, 0x44, 0x11)
+		);
+	}
+
+	#[test]
+	fn test_argb_round_trip() {
+		let rgba = Rgba::new(0x1e, 0x32, 0x3c, 0x80);
+		assert_eq!(rgba.to_argb(), 0x801e323c);
+		assert_eq!(Rgba::from_argb(rgba.to_argb()), rgba);
+		assert_eq!(Rgba::from_argb(0xff000000), Rgba::new(0, 0, 0, 255));
+	}
+
+	#[test]
+	fn test_alpha_formatting() {
+		let alpha = |alpha: u8| Rgba::new(0, 255, 255, alpha);
+
+		assert_eq!(alpha(255).to_css(), String::from("rgba(0, 255, 255, 1)"));
+		assert_eq!(alpha(128).to_css(), String::from("rgba(0, 255, 255, 0.5)"));
+		assert_eq!(alpha(85).to_css(), String::from("rgba(0, 255, 255, 0.333)"));
+		assert_eq!(alpha(255).to_string(), String::from("rgba(0, 255, 255, 1)"));
+		assert_eq!(
+			alpha(128).to_string(),
+			String::from("rgba(0, 255, 255, 0.5)")
+		);
+		assert_eq!(
+			alpha(85).to_string(),
+			String::from("rgba(0, 255, 255, 0.333)")
+		);
+
+		assert_eq!(
+			alpha(255).to_css_precise(3),
+			String::from("rgba(0, 255, 255, 1.000)")
+		);
+		assert_eq!(
+			alpha(128).to_css_precise(1),
+			String::from("rgba(0, 255, 255, 0.5)")
+		);
+		assert_eq!(
+			alpha(85).to_css_precise(3),
+			String::from("rgba(0, 255, 255, 0.333)")
+		);
+	}
+
+	#[test]
+	fn test_from_str() {
+		let rgba = "rgba(0, 255, 255, 0.5)".parse::<Rgba>().unwrap();
+		assert_eq!(rgba, Rgba::new(0, 255, 255, 128));
+
+		let rgba = " rgba(30,50,60,1) ".parse::<Rgba>().unwrap();
+		assert_eq!(rgba, Rgba::new(30, 50, 60, 255));
+
+		let rgba = "rgb(30, 50, 60)".parse::<Rgba>().unwrap();
+		assert_eq!(rgba, Rgba::new(30, 50, 60, 255));
+	}
+
+	#[test]
+	fn test_from_str_percentages() {
+		let rgba = "rgba(50%, 0%, 0%, 0.5)".parse::<Rgba>().unwrap();
+		assert_eq!(rgba, Rgba::new(128, 0, 0, 128));
+
+		let rgba = "rgb(50%, 0%, 0%)".parse::<Rgba>().unwrap();
+		assert_eq!(rgba, Rgba::new(128, 0, 0, 255));
+		assert_eq!(rgba.to_rgb(), "rgb(50%, 0%, 0%)".parse::<Rgb>().unwrap());
+
+		let rgba = "rgba(0, 255, 255, 50%)".parse::<Rgba>().unwrap();
+		assert_eq!(rgba, Rgba::new(0, 255, 255, 128));
+	}
+
+	#[test]
+	fn test_from_str_percentages_invalid() {
+		assert!(matches!(
+			"rgba(50%, 0, 0%, 0.5)".parse::<Rgba>(),
+			Err(Error::ParseError(_))
+		));
+		assert!(matches!(
+			"rgba(101%, 0%, 0%, 0.5)".parse::<Rgba>(),
+			Err(Error::ParseError(_))
+		));
+		assert!(matches!(
+			"rgba(0, 0, 0, 150%)".parse::<Rgba>(),
+			Err(Error::AlphaOverflow)
+		));
+	}
+
+	#[test]
+	fn test_from_str_alpha_overflow() {
+		assert!(matches!(
+			"rgba(0, 0, 0, 2.0)".parse::<Rgba>(),
+			Err(Error::AlphaOverflow)
+		));
+		assert!(matches!(
+			"rgba(0, 0, 0, -0.1)".parse::<Rgba>(),
+			Err(Error::AlphaOverflow)
+		));
+		assert!(matches!(
+			"rgba(0, 0, 0, 256)".parse::<Rgba>(),
+			Err(Error::AlphaOverflow)
+		));
+	}
+
+	#[test]
+	fn test_from_str_invalid() {
+		assert!(matches!(
+			"rgba(256, 0, 0, 0.5)".parse::<Rgba>(),
+			Err(Error::ParseError(_))
+		));
+		assert!(matches!(
+			"rgba(0, 0, 0.5)".parse::<Rgba>(),
+			Err(Error::ParseError(_))
+		));
+		assert!(matches!(
+			"hsla(0, 0%, 0%, 0.5)".parse::<Rgba>(),
+			Err(Error::ParseError(_))
+		));
+	}
+
+	#[test]
+	fn test_display_round_trip() {
+		let rgba = Rgba::new(30, 50, 60, 128);
+		assert_eq!(rgba.to_string().parse::<Rgba>().unwrap(), rgba);
+
+		for alpha in 0..=255 {
+			let rgba = Rgba::new(30, 50, 60, alpha);
+			assert_eq!(rgba.to_string().parse::<Rgba>().unwrap(), rgba);
+		}
+	}
+
+	#[test]
+	fn test_try_from_rgb() {
+		let rgb = Rgb::new(30, 50, 60);
+
+		assert_eq!(
+			Rgba::try_from_rgb(rgb, 0.).unwrap(),
+			Rgba::new(30, 50, 60, 0)
+		);
+		assert_eq!(
+			Rgba::try_from_rgb(rgb, 1.).unwrap(),
+			Rgba::new(30, 50, 60, 255)
+		);
+		assert!(matches!(
+			Rgba::try_from_rgb(rgb, -0.01),
+			Err(Error::AlphaOverflow)
+		));
+		assert!(matches!(
+			Rgba::try_from_rgb(rgb, 1.01),
+			Err(Error::AlphaOverflow)
+		));
+		assert!(matches!(
+			Rgba::try_from_rgb(rgb, f64::NAN),
+			Err(Error::AlphaOverflow)
+		));
+	}
+}
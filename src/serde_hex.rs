@@ -0,0 +1,114 @@
+use crate::prelude::*;
+use core::fmt;
+use serde::{
+	de::{self, Visitor},
+	Deserializer, Serializer,
+};
+
+///
+/// Serializes an [`Rgb`] as a hex string prefixed with a hashtag (`#`)
+/// rather than as a struct. Intended to be used with serde's
+/// `serialize_with` attribute, or together with [`deserialize`] through
+/// `#[serde(with = "color_conv::serde_hex")]`.
+///
+/// # Examples
+///
+/// ```
+/// use color_conv::Rgb;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Theme {
+///     #[serde(serialize_with = "color_conv::serde_hex::serialize")]
+///     accent: Rgb,
+/// }
+///
+/// let theme = Theme {
+///     accent: Rgb::new(30, 50, 60),
+/// };
+///
+/// assert_eq!(serde_json::to_string(&theme)?, r##"{"accent":"#1e323c"}"##);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+///
+pub fn serialize<S: Serializer>(rgb: &Rgb, serializer: S) -> Result<S::Ok, S::Error> {
+	serializer.serialize_str(&rgb.to_hex_string())
+}
+
+///
+/// Deserializes an [`Rgb`] from a hex string in any form accepted by
+/// [`Rgb::from_hex_str`], with or without the leading hashtag (`#`).
+/// Intended to be used with serde's `deserialize_with` attribute, or
+/// together with [`serialize`] through
+/// `#[serde(with = "color_conv::serde_hex")]`.
+///
+/// # Examples
+///
+/// ```
+/// use color_conv::Rgb;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Theme {
+///     #[serde(with = "color_conv::serde_hex")]
+///     accent: Rgb,
+/// }
+///
+/// let theme: Theme = serde_json::from_str(r##"{"accent":"#1e323c"}"##)?;
+/// assert_eq!(theme.accent, Rgb::new(30, 50, 60));
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+///
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Rgb, D::Error> {
+	deserializer.deserialize_str(HexVisitor)
+}
+
+struct HexVisitor;
+
+impl<'de> Visitor<'de> for HexVisitor {
+	type Value = Rgb;
+
+	fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str("a hex color string such as \"#1e323c\"")
+	}
+
+	fn visit_str<E: de::Error>(self, hex: &str) -> Result<Rgb, E> {
+		Rgb::from_hex_str(hex).map_err(E::custom)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use serde::{Deserialize, Serialize};
+
+	#[derive(Debug, PartialEq, Serialize, Deserialize)]
+	struct Theme {
+		#[serde(with = "crate::serde_hex")]
+		accent: Rgb,
+	}
+
+	#[test]
+	fn test_round_trip() {
+		let theme = Theme {
+			accent: Rgb::new(30, 50, 60),
+		};
+
+		let json = serde_json::to_string(&theme).unwrap();
+		assert_eq!(json, r##"{"accent":"#1e323c"}"##);
+		assert_eq!(serde_json::from_str::<Theme>(&json).unwrap(), theme);
+	}
+
+	#[test]
+	fn test_deserialize_without_hashtag() {
+		let theme: Theme = serde_json::from_str(r#"{"accent":"1e323c"}"#).unwrap();
+		assert_eq!(theme.accent, Rgb::new(30, 50, 60));
+	}
+
+	#[test]
+	fn test_deserialize_invalid() {
+		assert!(serde_json::from_str::<Theme>(r##"{"accent":"#1e323"}"##).is_err());
+		assert!(serde_json::from_str::<Theme>(r##"{"accent":"#zzzzzz"}"##).is_err());
+		assert!(serde_json::from_str::<Theme>(r#"{"accent":[30,50,60]}"#).is_err());
+	}
+}
@@ -0,0 +1,119 @@
+use crate::prelude::*;
+use core::fmt::Debug;
+
+///
+/// The largest per-channel error of an RGB → HSL → RGB round trip, for any
+/// color. Hue is stored in whole degrees and saturation and lightness in
+/// whole percents, so a channel can drift by a few steps.
+///
+/// # Examples
+///
+/// ```
+/// use color_conv::{testing, Hsl, Rgb};
+///
+/// testing::assert_roundtrip_within::<Hsl>(Rgb::new(230, 200, 200), testing::HSL_TOLERANCE);
+/// ```
+///
+pub const HSL_TOLERANCE: u8 = 5;
+
+///
+/// The largest per-channel error of an RGB → HSV → RGB round trip, for any
+/// color.
+///
+/// # Examples
+///
+/// ```
+/// use color_conv::{testing, Hsv, Rgb};
+///
+/// testing::assert_roundtrip_within::<Hsv>(Rgb::new(30, 50, 60), testing::HSV_TOLERANCE);
+/// ```
+///
+pub const HSV_TOLERANCE: u8 = 3;
+
+///
+/// The largest per-channel error of an RGB → CMYK → RGB round trip, for any
+/// color. Rounding a channel and the key to whole percents is each off by at
+/// most half a percent, so a channel can drift by a couple of steps.
+///
+/// # Examples
+///
+/// ```
+/// use color_conv::{testing, Cmyk, Rgb};
+///
+/// testing::assert_roundtrip_within::<Cmyk>(Rgb::new(30, 50, 60), testing::CMYK_TOLERANCE);
+/// ```
+///
+pub const CMYK_TOLERANCE: u8 = 2;
+
+///
+/// Returns the largest difference between corresponding channels of two
+/// colors, for comparing the results of conversions that may round
+/// differently.
+///
+/// # Arguments
+///
+/// * `a` - the first color
+/// * `b` - the second color
+///
+/// # Examples
+///
+/// ```
+/// use color_conv::{testing, Rgb};
+///
+/// let error = testing::max_channel_error(Rgb::new(30, 50, 60), Rgb::new(31, 47, 60));
+/// assert_eq!(error, 3);
+/// ```
+///
+pub fn max_channel_error(a: Rgb, b: Rgb) -> u8 {
+	[(a.red, b.red), (a.green, b.green), (a.blue, b.blue)]
+		.iter()
+		.map(|&(a, b)| (a as i16 - b as i16).unsigned_abs() as u8)
+		.max()
+		.unwrap()
+}
+
+///
+/// Converts `rgb` into `C` and back, panicking if any channel of the result
+/// differs from the original by more than `tolerance`. Pair this with the
+/// tolerance constants in this module to check that a pipeline stays within
+/// the precision the crate guarantees.
+///
+/// # Arguments
+///
+/// * `rgb` - the color to round trip
+/// * `tolerance` - the largest allowed per-channel difference
+///
+/// # Examples
+///
+/// ```
+/// use color_conv::{testing, Cmyk, Rgb};
+///
+/// testing::assert_roundtrip_within::<Cmyk>(Rgb::new(0, 255, 255), 0);
+/// ```
+///
+/// ```should_panic
+/// use color_conv::{testing, Hsl, Rgb};
+///
+/// // Rounding to whole percents loses the difference between 1 and 2
+/// testing::assert_roundtrip_within::<Hsl>(Rgb::new(1, 1, 2), 0);
+/// ```
+///
+pub fn assert_roundtrip_within<C>(rgb: Rgb, tolerance: u8)
+where
+	C: Color + From<Rgb> + Copy + Debug,
+{
+	let converted = C::from(rgb);
+	let back = converted.to_rgb();
+
+	let error = max_channel_error(rgb, back);
+
+	assert!(
+		error <= tolerance,
+		"{:?} round tripped through {:?} to {:?}, off by {} (tolerance {})",
+		rgb,
+		converted,
+		back,
+		error,
+		tolerance
+	);
+}
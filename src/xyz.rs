@@ -0,0 +1,183 @@
+use crate::{prelude::*, rgb};
+use core::fmt;
+
+/// The D65 reference white point, scaled so that `y` is 100
+pub(crate) const D65: Xyz = Xyz {
+	x: 95.047,
+	y: 100.,
+	z: 108.883,
+};
+
+///
+/// A representation of the CIE 1931 XYZ color space, using the D65 white
+/// point with values scaled so that white has a `y` of 100.
+///
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Xyz {
+	/// X value
+	pub x: f64,
+	/// Y value (luminance)
+	pub y: f64,
+	/// Z value
+	pub z: f64,
+}
+
+impl Xyz {
+	///
+	/// Returns a new Xyz object given x, y, and z values.
+	///
+	/// # Arguments
+	///
+	/// * `x` - the x value of the color
+	/// * `y` - the y value (luminance) of the color
+	/// * `z` - the z value of the color
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::Xyz;
+	/// let white = Xyz::new(95.047, 100., 108.883);
+	/// ```
+	///
+	pub const fn new(x: f64, y: f64, z: f64) -> Self {
+		Self { x, y, z }
+	}
+}
+
+impl fmt::Display for Xyz {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "xyz({:.2}, {:.2}, {:.2})", self.x, self.y, self.z)
+	}
+}
+
+impl Color for Xyz {
+	fn to_rgb(self) -> Rgb {
+		let (x, y, z) = (self.x / 100., self.y / 100., self.z / 100.);
+
+		let red = 3.2404542 * x - 1.5371385 * y - 0.4985314 * z;
+		let green = -0.969266 * x + 1.8760108 * y + 0.041556 * z;
+		let blue = 0.0556434 * x - 0.2040259 * y + 1.0572252 * z;
+
+		Rgb::new(
+			rgb::linear_to_srgb(red),
+			rgb::linear_to_srgb(green),
+			rgb::linear_to_srgb(blue),
+		)
+	}
+
+	fn to_cmyk(self) -> Cmyk {
+		self.to_rgb().to_cmyk()
+	}
+
+	fn to_hsl(self) -> Hsl {
+		self.to_rgb().to_hsl()
+	}
+
+	fn to_hsv(self) -> Hsv {
+		self.to_rgb().to_hsv()
+	}
+
+	fn to_xyz(self) -> Xyz {
+		self
+	}
+
+	fn to_lab(self) -> Lab {
+		let epsilon = (6f64 / 29.).powi(3);
+		let f = |t: f64| {
+			if t > epsilon {
+				t.cbrt()
+			} else {
+				t / (3. * (6f64 / 29.).powi(2)) + 4. / 29.
+			}
+		};
+
+		let fx = f(self.x / D65.x);
+		let fy = f(self.y / D65.y);
+		let fz = f(self.z / D65.z);
+
+		Lab::new(116. * fy - 16., 500. * (fx - fy), 200. * (fy - fz))
+	}
+
+	fn to_hex_string(self) -> String {
+		self.to_rgb().to_hex_string()
+	}
+
+	fn to_hwb(self) -> Hwb {
+		self.to_rgb().to_hwb()
+	}
+}
+
+impl Invert for Xyz {
+	fn invert(self) -> Xyz {
+		self.to_rgb().invert().to_xyz()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	fn assert_close(actual: Xyz, expected: Xyz) {
+		assert!(
+			(actual.x - expected.x).abs() < 0.01,
+			"{} != {}",
+			actual,
+			expected
+		);
+		assert!(
+			(actual.y - expected.y).abs() < 0.01,
+			"{} != {}",
+			actual,
+			expected
+		);
+		assert!(
+			(actual.z - expected.z).abs() < 0.01,
+			"{} != {}",
+			actual,
+			expected
+		);
+	}
+
+	#[test]
+	fn test_to_string() {
+		let xyz = Xyz::new(95.047, 100., 108.883);
+		assert_eq!(xyz.to_string(), String::from("xyz(95.05, 100.00, 108.88)"));
+	}
+
+	#[test]
+	fn test_from_rgb() {
+		assert_close(Rgb::new(255, 255, 255).to_xyz(), D65);
+		assert_close(
+			Rgb::new(128, 128, 128).to_xyz(),
+			Xyz::new(20.517, 21.586, 23.504),
+		);
+		assert_close(
+			Rgb::new(255, 0, 0).to_xyz(),
+			Xyz::new(41.246, 21.267, 1.933),
+		);
+	}
+
+	#[test]
+	fn test_to_rgb() {
+		assert_eq!(D65.to_rgb(), Rgb::new(255, 255, 255));
+		assert_eq!(
+			Xyz::new(41.246, 21.267, 1.933).to_rgb(),
+			Rgb::new(255, 0, 0)
+		);
+	}
+
+	#[test]
+	fn test_rgb_round_trip() {
+		for rgb in [
+			Rgb::new(30, 50, 60),
+			Rgb::new(204, 153, 102),
+			Rgb::new(0, 0, 0),
+			Rgb::new(0, 255, 255),
+		]
+		.iter()
+		{
+			assert_eq!(rgb.to_xyz().to_rgb(), *rgb);
+		}
+	}
+}
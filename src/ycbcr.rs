@@ -0,0 +1,212 @@
+use crate::prelude::*;
+use core::fmt;
+
+///
+/// The matrix used to derive luma and chroma from RGB, which determines how
+/// much each channel contributes to the luma.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum YCbCrMatrix {
+	/// ITU-R BT.601, used by standard-definition video and JPEG
+	Bt601,
+	/// ITU-R BT.709, used by high-definition video
+	Bt709,
+}
+
+impl YCbCrMatrix {
+	///
+	/// Returns the red and blue luma coefficients, `(Kr, Kb)`.
+	///
+	pub(crate) fn coefficients(self) -> (f64, f64) {
+		match self {
+			YCbCrMatrix::Bt601 => (0.299, 0.114),
+			YCbCrMatrix::Bt709 => (0.2126, 0.0722),
+		}
+	}
+}
+
+///
+/// The range of code values used to encode luma and chroma.
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum YCbCrRange {
+	/// Every channel uses the full range of 0 to 255, as in JPEG
+	Full,
+	/// Luma ranges from 16 to 235 and chroma from 16 to 240, as is typical
+	/// for broadcast video
+	Studio,
+}
+
+impl YCbCrRange {
+	///
+	/// Returns the luma offset, luma scale, and chroma scale, `(offset,
+	/// y_scale, c_scale)`.
+	///
+	pub(crate) fn scale(self) -> (f64, f64, f64) {
+		match self {
+			YCbCrRange::Full => (0., 255., 255.),
+			YCbCrRange::Studio => (16., 219., 224.),
+		}
+	}
+}
+
+///
+/// A representation of the Y′CbCr (luma, blue-difference chroma,
+/// red-difference chroma) color format used in video and image compression.
+/// The values don't record which [`YCbCrMatrix`] and [`YCbCrRange`] were
+/// used to encode them, so the same ones need to be passed when converting
+/// back to [`Rgb`].
+///
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct YCbCr {
+	/// Luma value
+	pub y: u8,
+	/// Blue-difference chroma value
+	pub cb: u8,
+	/// Red-difference chroma value
+	pub cr: u8,
+}
+
+impl YCbCr {
+	///
+	/// Returns a new YCbCr object given luma and chroma values.
+	///
+	/// # Arguments
+	///
+	/// * `y` - the luma value of the color
+	/// * `cb` - the blue-difference chroma value of the color
+	/// * `cr` - the red-difference chroma value of the color
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::YCbCr;
+	/// let white = YCbCr::new(255, 128, 128);
+	/// ```
+	///
+	pub const fn new(y: u8, cb: u8, cr: u8) -> Self {
+		Self { y, cb, cr }
+	}
+
+	///
+	/// Converts to [`Rgb`], assuming the values were encoded with the
+	/// BT.601 matrix at full range, as in JPEG. See [`YCbCr::to_rgb_with`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::{Rgb, YCbCr};
+	///
+	/// let red = YCbCr::new(76, 85, 255).to_rgb();
+	/// assert_eq!(red, Rgb::new(254, 0, 0));
+	/// ```
+	///
+	pub fn to_rgb(self) -> Rgb {
+		self.to_rgb_with(YCbCrMatrix::Bt601, YCbCrRange::Full)
+	}
+
+	///
+	/// Converts to [`Rgb`] using the given matrix and range, which should be
+	/// the ones the values were encoded with. Values outside of the range
+	/// are clamped.
+	///
+	/// # Arguments
+	///
+	/// * `matrix` - the matrix the values were encoded with
+	/// * `range` - the range the values were encoded with
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use color_conv::{Rgb, YCbCr, YCbCrMatrix, YCbCrRange};
+	///
+	/// let white = YCbCr::new(235, 128, 128).to_rgb_with(YCbCrMatrix::Bt709, YCbCrRange::Studio);
+	/// assert_eq!(white, Rgb::new(255, 255, 255));
+	/// ```
+	///
+	pub fn to_rgb_with(self, matrix: YCbCrMatrix, range: YCbCrRange) -> Rgb {
+		let (kr, kb) = matrix.coefficients();
+		let (offset, y_scale, c_scale) = range.scale();
+
+		let y = (self.y as f64 - offset) / y_scale;
+		let pb = (self.cb as f64 - 128.) / c_scale;
+		let pr = (self.cr as f64 - 128.) / c_scale;
+
+		let red = y + 2. * (1. - kr) * pr;
+		let blue = y + 2. * (1. - kb) * pb;
+		let green = (y - kr * red - kb * blue) / (1. - kr - kb);
+
+		let apply = |v: f64| (v * 255.).clamp(0., 255.).round() as u8;
+		Rgb::new(apply(red), apply(green), apply(blue))
+	}
+}
+
+impl fmt::Display for YCbCr {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "ycbcr({}, {}, {})", self.y, self.cb, self.cr)
+	}
+}
+
+impl From<Rgb> for YCbCr {
+	fn from(rgb: Rgb) -> Self {
+		rgb.to_ycbcr()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_to_string() {
+		let ycbcr = YCbCr::new(76, 85, 255);
+		assert_eq!(ycbcr.to_string(), String::from("ycbcr(76, 85, 255)"));
+	}
+
+	#[test]
+	fn test_bt601_full_reference() {
+		assert_eq!(
+			Rgb::new(255, 255, 255).to_ycbcr(),
+			YCbCr::new(255, 128, 128)
+		);
+		assert_eq!(Rgb::new(0, 0, 0).to_ycbcr(), YCbCr::new(0, 128, 128));
+		assert_eq!(Rgb::new(255, 0, 0).to_ycbcr(), YCbCr::new(76, 85, 255));
+		assert_eq!(Rgb::new(0, 0, 255).to_ycbcr(), YCbCr::new(29, 255, 107));
+	}
+
+	#[test]
+	fn test_bt709_studio_reference() {
+		let convert = |rgb: Rgb| rgb.to_ycbcr_with(YCbCrMatrix::Bt709, YCbCrRange::Studio);
+
+		assert_eq!(convert(Rgb::new(255, 255, 255)), YCbCr::new(235, 128, 128));
+		assert_eq!(convert(Rgb::new(0, 0, 0)), YCbCr::new(16, 128, 128));
+		assert_eq!(convert(Rgb::new(255, 0, 0)), YCbCr::new(63, 102, 240));
+		assert_eq!(convert(Rgb::new(0, 255, 0)), YCbCr::new(173, 42, 26));
+	}
+
+	#[test]
+	fn test_round_trip() {
+		for &matrix in [YCbCrMatrix::Bt601, YCbCrMatrix::Bt709].iter() {
+			for &range in [YCbCrRange::Full, YCbCrRange::Studio].iter() {
+				for rgb in [
+					Rgb::new(255, 255, 255),
+					Rgb::new(0, 0, 0),
+					Rgb::new(30, 50, 60),
+					Rgb::new(204, 153, 102),
+				]
+				.iter()
+				{
+					let back = rgb.to_ycbcr_with(matrix, range).to_rgb_with(matrix, range);
+					assert!(back.distance(*rgb) < 3., "{:?} became {:?}", rgb, back);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_from_rgb() {
+		let ycbcr: YCbCr = Rgb::new(255, 255, 255).into();
+		assert_eq!(ycbcr, YCbCr::new(255, 128, 128));
+	}
+}
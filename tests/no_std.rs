@@ -0,0 +1,43 @@
+//!
+//! Exercises the crate when built without the `std` feature, where all float
+//! math goes through `libm`. Run with `cargo test --no-default-features`.
+//! This still runs on the host with the `std` test harness, so the actual
+//! `no_std` build is checked separately in CI against a bare-metal target.
+//!
+
+#![cfg(not(feature = "std"))]
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::ToString;
+use color_conv::prelude::*;
+
+#[test]
+fn test_conversions() {
+	let rgb = Rgb::new(204, 153, 102);
+
+	assert_eq!(rgb.to_hsl(), Hsl::new_unchecked(30, 50, 60));
+	assert_eq!(rgb.to_hsv(), Hsv::new_unchecked(30, 50, 80));
+	assert_eq!(rgb.to_hwb(), Hwb::new_unchecked(30, 40, 20));
+	assert_eq!(rgb.to_hsl().to_rgb(), rgb);
+	assert_eq!(rgb.to_hsv().to_rgb(), rgb);
+	assert_eq!(rgb.to_lab().to_rgb(), rgb);
+	assert_eq!(
+		Cmyk::new_unchecked(100, 0, 0, 0).to_rgb(),
+		Rgb::new(0, 255, 255)
+	);
+}
+
+#[test]
+fn test_formatting() {
+	let rgb = Rgb::new(0, 255, 255);
+
+	assert_eq!(rgb.to_hex_string(), "#00ffff");
+	assert_eq!(rgb.to_string(), "rgb(0, 255, 255)");
+	assert_eq!("rgb(0, 255, 255)".parse::<Rgb>().unwrap(), rgb);
+	assert_eq!(
+		Error::InvalidHex.to_string(),
+		"Invalid hex: string is not a valid hex color!"
+	);
+}